@@ -0,0 +1,55 @@
+//! A string buffer on the stack, for formatting without an allocator.
+
+use core::fmt::{self, Write};
+
+/// A string of at most `N` bytes that is kept on the stack.
+pub(crate) struct Buffer<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Buffer<N> {
+    /// Format arguments into a buffer, or return `None` if they don't fit.
+    pub(crate) fn format(arguments: fmt::Arguments<'_>) -> Option<Self> {
+        let mut buffer = Self {
+            bytes: [0; N],
+            len: 0,
+        };
+        buffer.write_fmt(arguments).ok()?;
+        Some(buffer)
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        // Only whole strings are ever written, so the bytes are always valid.
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl<const N: usize> Write for Buffer<N> {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        let len = self.len + string.len();
+        if len > N {
+            return Err(fmt::Error);
+        }
+
+        self.bytes[self.len..len].copy_from_slice(string.as_bytes());
+        self.len = len;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(format_args!("{}", 1.91), Some("1.91"))]
+    #[test_case(format_args!("{:+}", -112.5), Some("-112.5"))]
+    #[test_case(format_args!("{}", "12345678"), Some("12345678"))]
+    #[test_case(format_args!("{}", "123456789"), None)]
+    fn format(arguments: fmt::Arguments<'_>, expected: Option<&str>) {
+        let buffer = Buffer::<8>::format(arguments);
+        assert_eq!(buffer.as_ref().map(Buffer::as_str), expected);
+    }
+}
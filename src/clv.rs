@@ -0,0 +1,252 @@
+//! Closing line value (CLV), i.e. how much better the price a bet was placed at is than
+//! the price at which the market closed.
+//!
+//! The closing price is the most accurate estimate of the true probability, so beating
+//! it consistently is a sign of skill, even over too few bets for the profit to be.
+//!
+//! Example
+//! ```rust
+//! use wager::clv::{Clv, ClvSummary};
+//! use wager::odds;
+//!
+//! let clv = Clv::new(odds!(+120), odds!(+100));
+//! assert!((clv.percentage() - 0.1).abs() < 1e-12);
+//! assert!((clv.probability() - (0.5 - 1.0 / 2.2)).abs() < 1e-12);
+//!
+//! let summary: ClvSummary = [clv, Clv::new(odds!(1.9), odds!(2.0))].into_iter().collect();
+//! assert_eq!(summary.count(), 2);
+//! assert_eq!(summary.beat_rate(), Some(0.5));
+//! ```
+
+#[cfg(feature = "alloc")]
+use crate::market::{Consensus, Market};
+use crate::odd::{AnyOdd, Odd};
+
+/// The closing line value of a bet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Clv {
+    taken: AnyOdd,
+    closing_probability: f64,
+}
+
+impl Clv {
+    /// Compare the odd a bet was taken at with the closing odd, including the
+    /// bookmaker's margin.
+    pub fn new(taken: impl Into<AnyOdd>, closing: impl Odd) -> Self {
+        Self::with_probability(taken, closing.payout(1.0).recip())
+    }
+
+    /// Compare the odd a bet was taken at with the closing probability of the
+    /// selection, e.g. with the margin removed.
+    pub fn with_probability(taken: impl Into<AnyOdd>, closing_probability: f64) -> Self {
+        Self {
+            taken: taken.into(),
+            closing_probability,
+        }
+    }
+
+    /// Compare the odd a bet was taken at with the fair closing price of a selection of
+    /// a market, from a [`Consensus`] of its closing prices.
+    ///
+    /// Returns `None` if the market has no such selection or no consensus.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::clv::Clv;
+    /// use wager::market::{Consensus, Market, MarketType, Price, Selection, Timestamp};
+    /// use wager::odds;
+    ///
+    /// let close = Timestamp::from_millis(0);
+    /// let market = Market::new(MarketType::Moneyline)
+    ///     .with_selection(Selection::new("Home").with_price(Price::new(odds!(-110), "a", close)))
+    ///     .with_selection(Selection::new("Away").with_price(Price::new(odds!(-110), "a", close)));
+    ///
+    /// let clv = Clv::with_market(odds!(+105), &market, "Home", &Consensus::new()).unwrap();
+    /// assert_eq!(clv.closing_probability(), 0.5);
+    /// assert!((clv.percentage() - 0.025).abs() < 1e-12);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn with_market(
+        taken: impl Into<AnyOdd>,
+        closing: &Market,
+        selection: &str,
+        consensus: &Consensus,
+    ) -> Option<Self> {
+        let (_, fair) = consensus
+            .fair_prices(closing)?
+            .into_iter()
+            .find(|(name, _)| *name == selection)?;
+
+        Some(Self::with_probability(taken, fair.probability()))
+    }
+
+    /// Get the odd the bet was taken at.
+    pub fn taken(&self) -> AnyOdd {
+        self.taken
+    }
+
+    /// Get the closing probability of the selection.
+    pub fn closing_probability(&self) -> f64 {
+        self.closing_probability
+    }
+
+    /// Get the CLV as a percentage of the closing price, i.e. the expected return on
+    /// investment if the closing probability is true, e.g. `0.05` for 5%.
+    pub fn percentage(&self) -> f64 {
+        self.taken.payout(self.closing_probability) - 1.0
+    }
+
+    /// Get the CLV in terms of implied probability, i.e. how much the closing
+    /// probability exceeds the probability implied by the odd taken.
+    pub fn probability(&self) -> f64 {
+        self.closing_probability - self.taken.payout(1.0).recip()
+    }
+
+    /// Check whether the bet was taken at a better price than the close.
+    pub fn is_positive(&self) -> bool {
+        self.percentage() > 0.0
+    }
+}
+
+/// The closing line value of a history of bets.
+///
+/// Summaries can be collected from, or [extended](Extend) with, the CLV of each bet.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ClvSummary {
+    count: usize,
+    beaten: usize,
+    percentage: f64,
+    probability: f64,
+}
+
+impl ClvSummary {
+    /// Create a summary without any bets.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the CLV of a bet.
+    pub fn add(&mut self, clv: Clv) {
+        self.count += 1;
+        self.beaten += usize::from(clv.is_positive());
+        self.percentage += clv.percentage();
+        self.probability += clv.probability();
+    }
+
+    /// Get the number of bets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Get the mean CLV as a percentage, or `None` without any bets.
+    pub fn mean_percentage(&self) -> Option<f64> {
+        self.mean(self.percentage)
+    }
+
+    /// Get the mean CLV in terms of implied probability, or `None` without any bets.
+    pub fn mean_probability(&self) -> Option<f64> {
+        self.mean(self.probability)
+    }
+
+    /// Get the fraction of bets that beat the closing price, or `None` without any
+    /// bets.
+    pub fn beat_rate(&self) -> Option<f64> {
+        self.mean(self.beaten as f64)
+    }
+
+    fn mean(&self, total: f64) -> Option<f64> {
+        (self.count > 0).then(|| total / self.count as f64)
+    }
+}
+
+impl Extend<Clv> for ClvSummary {
+    fn extend<T: IntoIterator<Item = Clv>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|clv| self.add(clv));
+    }
+}
+
+impl FromIterator<Clv> for ClvSummary {
+    fn from_iter<T: IntoIterator<Item = Clv>>(iter: T) -> Self {
+        let mut summary = Self::new();
+        summary.extend(iter);
+        summary
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::odds;
+    use crate::testing::assert_close;
+    use test_case::test_case;
+
+    #[test_case(AnyOdd::from(odds!(2.2)), AnyOdd::from(odds!(2.0)), 0.1, 0.5 - 1.0 / 2.2)]
+    #[test_case(AnyOdd::from(odds!(-200)), AnyOdd::from(odds!(-400)), 0.2, 0.8 - 2.0 / 3.0)]
+    #[test_case(AnyOdd::from(odds!(3/1)), AnyOdd::from(odds!(4/1)), -0.2, -0.05)]
+    #[test_case(AnyOdd::from(odds!(+150)), AnyOdd::from(odds!(3/2)), 0.0, 0.0)]
+    fn clv(taken: AnyOdd, closing: AnyOdd, percentage: f64, probability: f64) {
+        let clv = Clv::new(taken, closing);
+
+        assert_close(clv.percentage(), percentage);
+        assert_close(clv.probability(), probability);
+        assert_eq!(clv.is_positive(), percentage > 1e-12);
+        assert_eq!(clv.taken(), taken);
+    }
+
+    #[test]
+    fn with_probability() {
+        let clv = Clv::with_probability(odds!(1.95), 0.52);
+
+        assert_eq!(clv.closing_probability(), 0.52);
+        assert_close(clv.percentage(), 1.95 * 0.52 - 1.0);
+        assert_close(clv.probability(), 0.52 - 1.0 / 1.95);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn with_market() {
+        use crate::market::{DeVig, MarketType, Price, Selection, Timestamp};
+
+        let close = Timestamp::EPOCH;
+        let market = Market::new(MarketType::OneXTwo)
+            .with_selection(Selection::new("Home").with_price(Price::new(odds!(2.0), "a", close)))
+            .with_selection(Selection::new("Draw").with_price(Price::new(odds!(4.0), "a", close)))
+            .with_selection(Selection::new("Away").with_price(Price::new(odds!(4.0), "a", close)));
+
+        // The closing market has a margin of 0.25 + 0.25 + 0.5 - 1 = 0.
+        let consensus = Consensus::new().with_method(DeVig::Power);
+        let clv = Clv::with_market(odds!(4.5), &market, "Draw", &consensus).unwrap();
+        assert_close(clv.closing_probability(), 0.25);
+        assert_close(clv.percentage(), 0.125);
+
+        assert!(Clv::with_market(odds!(4.5), &market, "Nobody", &consensus).is_none());
+        let empty = Market::new(MarketType::OneXTwo).with_selection(Selection::new("Home"));
+        assert!(Clv::with_market(odds!(4.5), &empty, "Home", &consensus).is_none());
+    }
+
+    #[test]
+    fn summary() {
+        let mut summary = ClvSummary::new();
+        assert_eq!(summary.mean_percentage(), None);
+        assert_eq!(summary.beat_rate(), None);
+
+        summary.extend([
+            Clv::new(odds!(2.2), odds!(2.0)),
+            Clv::new(odds!(1.8), odds!(2.0)),
+            Clv::new(odds!(3.3), odds!(3.0)),
+        ]);
+        summary.add(Clv::new(odds!(2.0), odds!(2.0)));
+
+        assert_eq!(summary.count(), 4);
+        assert_close(
+            summary.mean_percentage().unwrap(),
+            (0.1 - 0.1 + 0.1 + 0.0) / 4.0,
+        );
+        assert_close(
+            summary.mean_probability().unwrap(),
+            (0.5 - 1.0 / 2.2 + 0.5 - 1.0 / 1.8 + 1.0 / 3.0 - 1.0 / 3.3) / 4.0,
+        );
+        assert_eq!(summary.beat_rate(), Some(0.5));
+    }
+}
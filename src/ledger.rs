@@ -0,0 +1,406 @@
+//! A history of bets and the statistics of their performance.
+//!
+//! Statistics are of the bets that have been won or lost, so pending and void bets
+//! are left out. Those that depend on the order of the bets, like the
+//! [drawdown](Ledger::max_drawdown), follow the order in which bets were settled.
+//!
+//! Requires the `alloc` feature. With the `serde` feature, every type can be
+//! (de)serialized.
+//!
+//! Example
+//! ```rust
+//! use wager::ledger::{Bet, Ledger, Outcome};
+//! use wager::market::Timestamp;
+//! use wager::odds;
+//!
+//! let at = Timestamp::from_millis;
+//! let ledger = Ledger::new()
+//!     .with_bet(Bet::new(odds!(2.0), 10.0, at(1))?.with_outcome(Outcome::Lost, at(2)))
+//!     .with_bet(Bet::new(odds!(3.0), 10.0, at(1))?.with_outcome(Outcome::Won, at(3)))
+//!     .with_bet(Bet::new(odds!(1.5), 10.0, at(4))?);
+//!
+//! assert_eq!(ledger.profit(), 10.0);
+//! assert_eq!(ledger.turnover(), 20.0);
+//! assert_eq!(ledger.yield_rate(), Some(0.5));
+//! assert_eq!(ledger.strike_rate(), Some(0.5));
+//! assert_eq!(ledger.max_drawdown(), 10.0);
+//! # Ok::<(), wager::ledger::BetError>(())
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::math::{exp, sqrt};
+use crate::odd::Odd;
+
+mod bet;
+
+pub use bet::{Bet, BetError, Outcome};
+
+/// A record of bets, in the order they were added.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ledger {
+    bets: Vec<Bet>,
+}
+
+impl Ledger {
+    /// Create a ledger without any bets.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a bet, returning the ledger.
+    pub fn with_bet(mut self, bet: Bet) -> Self {
+        self.add_bet(bet);
+        self
+    }
+
+    /// Add a bet.
+    pub fn add_bet(&mut self, bet: Bet) {
+        self.bets.push(bet);
+    }
+
+    /// Get every bet, in the order they were added.
+    pub fn bets(&self) -> &[Bet] {
+        &self.bets
+    }
+
+    /// Get every bet mutably, e.g. to settle them.
+    pub fn bets_mut(&mut self) -> &mut [Bet] {
+        &mut self.bets
+    }
+
+    /// Get the total profit, which is negative for a loss.
+    pub fn profit(&self) -> f64 {
+        self.bets.iter().map(Bet::profit).sum()
+    }
+
+    /// Get the total stake of the bets that were won or lost.
+    pub fn turnover(&self) -> f64 {
+        self.decided().map(Bet::stake).sum()
+    }
+
+    /// Get the yield, i.e. the profit as a fraction of the turnover, or `None` without
+    /// any bets that were won or lost.
+    pub fn yield_rate(&self) -> Option<f64> {
+        let turnover = self.turnover();
+        (turnover != 0.0).then(|| self.profit() / turnover)
+    }
+
+    /// Get the return on investment of the average bet, i.e. the mean of each bet's
+    /// profit as a fraction of its stake, or `None` without any bets that were won or
+    /// lost.
+    ///
+    /// Unlike the [yield](Ledger::yield_rate), every bet counts equally regardless of
+    /// its stake.
+    pub fn roi(&self) -> Option<f64> {
+        mean(self.decided().map(|bet| bet.profit() / bet.stake()))
+    }
+
+    /// Get the fraction of bets that were won, of those that were won or lost.
+    pub fn strike_rate(&self) -> Option<f64> {
+        mean(
+            self.decided()
+                .map(|bet| f64::from(u8::from(bet.outcome() == Outcome::Won))),
+        )
+    }
+
+    /// Get the mean decimal odd of the bets that were won or lost.
+    pub fn average_odd(&self) -> Option<f64> {
+        mean(self.decided().map(|bet| bet.odd().payout(1.0)))
+    }
+
+    /// Get the largest fall of the cumulative profit from a previous peak, including
+    /// the start of the ledger.
+    pub fn max_drawdown(&self) -> f64 {
+        let (mut profit, mut peak, mut drawdown) = (0.0, 0.0, 0.0_f64);
+        for bet in self.in_settlement_order() {
+            profit += bet.profit();
+            peak = f64::max(peak, profit);
+            drawdown = drawdown.max(peak - profit);
+        }
+
+        drawdown
+    }
+
+    /// Get the largest number of bets that were lost in a row.
+    pub fn longest_losing_streak(&self) -> usize {
+        let (mut streak, mut longest) = (0, 0);
+        for bet in self.in_settlement_order() {
+            streak = match bet.outcome() {
+                Outcome::Lost => streak + 1,
+                _ => 0,
+            };
+            longest = longest.max(streak);
+        }
+
+        longest
+    }
+
+    /// Test whether the profit is significantly better than if each bet won with the
+    /// probability implied by its odd, i.e. if there were no edge.
+    ///
+    /// Returns `None` without any bets that were won or lost at odds above `1.0`.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::ledger::{Bet, Ledger, Outcome};
+    /// use wager::market::Timestamp;
+    /// use wager::odds;
+    ///
+    /// let mut ledger = Ledger::new();
+    /// for i in 0..100 {
+    ///     let outcome = if i % 5 < 3 { Outcome::Won } else { Outcome::Lost };
+    ///     let bet = Bet::new(odds!(2.0), 1.0, Timestamp::EPOCH).unwrap();
+    ///     ledger.add_bet(bet.with_outcome(outcome, Timestamp::EPOCH));
+    /// }
+    ///
+    /// // Winning 60% at evens is twice the standard deviation above breaking even.
+    /// let significance = ledger.significance().unwrap();
+    /// assert!((significance.z_score() - 2.0).abs() < 1e-12);
+    /// assert!(significance.p_value() < 0.05);
+    /// ```
+    pub fn significance(&self) -> Option<Significance> {
+        // Without an edge, the profit of a bet at decimal odd `d` has a mean of zero and
+        // a variance of `stake² (d - 1)`.
+        let (profit, variance) = self.decided().fold((0.0, 0.0), |(profit, variance), bet| {
+            let odd = bet.odd().payout(1.0);
+            (
+                profit + bet.profit(),
+                variance + bet.stake() * bet.stake() * (odd - 1.0),
+            )
+        });
+
+        if variance <= 0.0 {
+            return None;
+        }
+
+        let z_score = profit / sqrt(variance);
+        Some(Significance {
+            z_score,
+            p_value: upper_tail(z_score),
+        })
+    }
+
+    /// Iterate over the bets that were won or lost, in the order they were added.
+    fn decided(&self) -> impl Iterator<Item = &Bet> {
+        self.bets
+            .iter()
+            .filter(|bet| matches!(bet.outcome(), Outcome::Won | Outcome::Lost))
+    }
+
+    /// Get the bets that were won or lost, in the order they were settled.
+    fn in_settlement_order(&self) -> Vec<&Bet> {
+        let mut bets: Vec<&Bet> = self.decided().collect();
+        bets.sort_by_key(|bet| bet.settled());
+        bets
+    }
+}
+
+/// The result of testing whether a ledger's profit is due to chance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Significance {
+    z_score: f64,
+    p_value: f64,
+}
+
+impl Significance {
+    /// Get the number of standard deviations that the profit is above zero.
+    pub fn z_score(&self) -> f64 {
+        self.z_score
+    }
+
+    /// Get the probability of making at least the profit without an edge, by the
+    /// normal approximation. A low value, e.g. below `0.05`, is significant.
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (count, total) = values.fold((0usize, 0.0), |(count, total), value| {
+        (count + 1, total + value)
+    });
+
+    (count > 0).then(|| total / count as f64)
+}
+
+/// Get the probability that a standard normal variable is at least `z`.
+fn upper_tail(z: f64) -> f64 {
+    erfc(z / core::f64::consts::SQRT_2) / 2.0
+}
+
+/// The complementary error function, by a Chebyshev approximation with a relative
+/// error below 1.2e-7 (Numerical Recipes, 2nd edition, section 6.2).
+fn erfc(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.5 * x.abs());
+    let polynomial = [
+        -1.26551223,
+        1.00002368,
+        0.37409196,
+        0.09678418,
+        -0.18628806,
+        0.27886807,
+        -1.13520398,
+        1.48851587,
+        -0.82215223,
+        0.17087277,
+    ]
+    .iter()
+    .rev()
+    .fold(0.0, |sum, coefficient| coefficient + t * sum);
+
+    let value = t * exp(-x * x + polynomial);
+    if x >= 0.0 {
+        value
+    } else {
+        2.0 - value
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::market::Timestamp;
+    use crate::odd::AnyOdd;
+    use crate::testing::{self, assert_close};
+    use test_case::test_case;
+
+    fn bet(odd: &str, stake: f64, outcome: Outcome, settled: u64) -> Bet {
+        Bet::new(testing::odd(odd), stake, Timestamp::EPOCH)
+            .unwrap()
+            .with_outcome(outcome, Timestamp::from_millis(settled))
+    }
+
+    fn ledger() -> Ledger {
+        Ledger::new()
+            .with_bet(bet("2.0", 10.0, Outcome::Won, 1))
+            .with_bet(bet("+150", 10.0, Outcome::Lost, 2))
+            .with_bet(bet("-200", 20.0, Outcome::Lost, 4))
+            .with_bet(bet("4/1", 5.0, Outcome::Won, 6))
+            .with_bet(bet("3.0", 10.0, Outcome::Void, 3))
+            .with_bet(bet("3.0", 10.0, Outcome::Lost, 5))
+            .with_bet(Bet::new(AnyOdd::from(crate::odds!(1.5)), 50.0, Timestamp::EPOCH).unwrap())
+    }
+
+    #[test]
+    fn settle() {
+        let mut bet = bet("2.0", 10.0, Outcome::Won, 1);
+        assert!(bet.is_settled());
+        assert_eq!(bet.profit(), 10.0);
+
+        bet.settle(Outcome::Void, Timestamp::from_millis(2));
+        assert_eq!(bet.profit(), 0.0);
+        assert_eq!(bet.settled(), Some(Timestamp::from_millis(2)));
+
+        bet.settle(Outcome::Pending, Timestamp::from_millis(3));
+        assert!(!bet.is_settled());
+        assert_eq!(bet.settled(), None);
+    }
+
+    #[test_case(0.0, BetError::StakeNotPositive)]
+    #[test_case(-10.0, BetError::StakeNotPositive)]
+    #[test_case(f64::NAN, BetError::StakeNotFinite)]
+    #[test_case(f64::INFINITY, BetError::StakeNotFinite)]
+    #[test_case(f64::NEG_INFINITY, BetError::StakeNotFinite)]
+    fn invalid_stake(stake: f64, expected: BetError) {
+        let bet = Bet::new(testing::odd("2.0"), stake, Timestamp::EPOCH);
+        assert_eq!(bet, Err(expected));
+    }
+
+    #[test_case(Outcome::Won, 15.0)]
+    #[test_case(Outcome::Lost, -10.0)]
+    #[test_case(Outcome::Void, 0.0)]
+    #[test_case(Outcome::Pending, 0.0)]
+    fn profit(outcome: Outcome, expected: f64) {
+        assert_eq!(bet("+150", 10.0, outcome, 1).profit(), expected);
+    }
+
+    #[test]
+    fn statistics() {
+        let ledger = ledger();
+
+        assert_eq!(ledger.bets().len(), 7);
+        assert_eq!(ledger.profit(), 10.0 - 10.0 - 20.0 + 20.0 - 10.0);
+        assert_eq!(ledger.turnover(), 55.0);
+        assert_eq!(ledger.yield_rate(), Some(-10.0 / 55.0));
+        assert_eq!(ledger.roi(), Some((1.0 - 1.0 - 1.0 + 4.0 - 1.0) / 5.0));
+        assert_eq!(ledger.strike_rate(), Some(0.4));
+        assert_eq!(
+            ledger.average_odd(),
+            Some((2.0 + 2.5 + 1.5 + 5.0 + 3.0) / 5.0)
+        );
+    }
+
+    #[test]
+    fn settlement_order() {
+        let ledger = ledger();
+
+        // In order of settlement the profit goes 10, 0, -20, -30, -10.
+        assert_eq!(ledger.max_drawdown(), 40.0);
+        assert_eq!(ledger.longest_losing_streak(), 3);
+    }
+
+    #[test]
+    fn empty() {
+        let mut ledger = Ledger::new()
+            .with_bet(Bet::new(AnyOdd::from(crate::odds!(2.0)), 10.0, Timestamp::EPOCH).unwrap());
+
+        assert_eq!(ledger.profit(), 0.0);
+        assert_eq!(ledger.yield_rate(), None);
+        assert_eq!(ledger.roi(), None);
+        assert_eq!(ledger.strike_rate(), None);
+        assert_eq!(ledger.average_odd(), None);
+        assert_eq!(ledger.max_drawdown(), 0.0);
+        assert_eq!(ledger.longest_losing_streak(), 0);
+        assert_eq!(ledger.significance(), None);
+
+        ledger.bets_mut()[0].settle(Outcome::Won, Timestamp::from_millis(1));
+        assert_eq!(ledger.strike_rate(), Some(1.0));
+    }
+
+    #[test]
+    fn significance() {
+        let ledger = Ledger::new()
+            .with_bet(bet("3.0", 10.0, Outcome::Won, 1))
+            .with_bet(bet("3.0", 10.0, Outcome::Lost, 2))
+            .with_bet(bet("5/1", 2.0, Outcome::Lost, 3));
+
+        let significance = ledger.significance().unwrap();
+        let z_score = (20.0 - 10.0 - 2.0) / f64::sqrt(100.0 * 2.0 + 100.0 * 2.0 + 4.0 * 5.0);
+        assert_close(significance.z_score(), z_score);
+        assert!(significance.p_value() > 0.05);
+
+        let evens = Ledger::new().with_bet(bet("1.0", 10.0, Outcome::Won, 1));
+        assert_eq!(evens.significance(), None);
+    }
+
+    #[test_case(0.0, 0.5)]
+    #[test_case(1.0, 0.158655254)]
+    #[test_case(1.959963985, 0.025)]
+    #[test_case(-1.644853627, 0.95)]
+    #[test_case(3.0, 0.001349898)]
+    fn normal_upper_tail(z: f64, expected: f64) {
+        assert!((upper_tail(z) - expected).abs() < 1e-7);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let ledger = ledger();
+        let json = serde_json::to_string(&ledger).unwrap();
+        assert_eq!(serde_json::from_str::<Ledger>(&json).unwrap(), ledger);
+
+        let bytes = bincode::serialize(&ledger).unwrap();
+        assert_eq!(bincode::deserialize::<Ledger>(&bytes).unwrap(), ledger);
+
+        let bet: Bet =
+            serde_json::from_str(r#"{"odd":{"decimal":2.0},"stake":1.0,"placed":0}"#).unwrap();
+        assert_eq!(bet.outcome(), Outcome::Pending);
+
+        let bet = serde_json::from_str::<Bet>(r#"{"odd":{"decimal":2.0},"stake":0.0,"placed":0}"#);
+        assert!(bet.is_err());
+    }
+}
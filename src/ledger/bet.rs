@@ -0,0 +1,176 @@
+use derive_more::Display;
+
+use crate::market::Timestamp;
+use crate::odd::{AnyOdd, Odd};
+
+/// The result of a bet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Outcome {
+    /// The bet hasn't been settled yet.
+    #[default]
+    Pending,
+    /// The bet won, and was paid out at its odd.
+    Won,
+    /// The bet lost its stake.
+    Lost,
+    /// The bet was cancelled or pushed, and its stake returned.
+    Void,
+}
+
+/// A bet recorded in a [`Ledger`](super::Ledger).
+///
+/// Example
+/// ```rust
+/// use wager::ledger::{Bet, Outcome};
+/// use wager::market::Timestamp;
+/// use wager::odds;
+///
+/// let mut bet = Bet::new(odds!(+150), 10.0, Timestamp::from_millis(1)).unwrap();
+/// assert_eq!(bet.profit(), 0.0);
+///
+/// bet.settle(Outcome::Won, Timestamp::from_millis(2));
+/// assert_eq!(bet.profit(), 15.0);
+/// assert_eq!(bet.settled(), Some(Timestamp::from_millis(2)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "BetFields"))]
+pub struct Bet {
+    odd: AnyOdd,
+    stake: f64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    outcome: Outcome,
+    placed: Timestamp,
+    #[cfg_attr(feature = "serde", serde(default))]
+    settled: Option<Timestamp>,
+}
+
+impl Bet {
+    /// Create a new pending bet.
+    ///
+    /// This will error if the stake is not a positive, finite number.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::ledger::{Bet, BetError};
+    /// use wager::market::Timestamp;
+    /// use wager::odds;
+    ///
+    /// let bet = Bet::new(odds!(2.0), 0.0, Timestamp::EPOCH);
+    /// assert_eq!(bet, Err(BetError::StakeNotPositive));
+    /// ```
+    pub fn new(odd: impl Into<AnyOdd>, stake: f64, placed: Timestamp) -> Result<Self, BetError> {
+        if !stake.is_finite() {
+            return Err(BetError::StakeNotFinite);
+        }
+
+        if stake <= 0.0 {
+            return Err(BetError::StakeNotPositive);
+        }
+
+        Ok(Self {
+            odd: odd.into(),
+            stake,
+            outcome: Outcome::Pending,
+            placed,
+            settled: None,
+        })
+    }
+
+    /// Settle the bet, returning it.
+    pub fn with_outcome(mut self, outcome: Outcome, settled: Timestamp) -> Self {
+        self.settle(outcome, settled);
+        self
+    }
+
+    /// Settle the bet, or change its outcome if it was already settled.
+    ///
+    /// Settling a bet as [`Outcome::Pending`] reopens it.
+    pub fn settle(&mut self, outcome: Outcome, settled: Timestamp) {
+        self.outcome = outcome;
+        self.settled = (outcome != Outcome::Pending).then_some(settled);
+    }
+
+    /// Get the odd the bet was placed at.
+    pub fn odd(&self) -> AnyOdd {
+        self.odd
+    }
+
+    /// Get the stake of the bet.
+    pub fn stake(&self) -> f64 {
+        self.stake
+    }
+
+    /// Get the outcome of the bet.
+    pub fn outcome(&self) -> Outcome {
+        self.outcome
+    }
+
+    /// Get the time at which the bet was placed.
+    pub fn placed(&self) -> Timestamp {
+        self.placed
+    }
+
+    /// Get the time at which the bet was settled, if it has been.
+    pub fn settled(&self) -> Option<Timestamp> {
+        self.settled
+    }
+
+    /// Check whether the bet has been settled.
+    pub fn is_settled(&self) -> bool {
+        self.outcome != Outcome::Pending
+    }
+
+    /// Get the profit of the bet, which is negative for a loss and zero unless it has
+    /// been won or lost.
+    pub fn profit(&self) -> f64 {
+        match self.outcome {
+            Outcome::Won => self.odd.payout(self.stake) - self.stake,
+            Outcome::Lost => -self.stake,
+            Outcome::Pending | Outcome::Void => 0.0,
+        }
+    }
+}
+
+/// An error that can occur when creating a [`Bet`].
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BetError {
+    /// The stake of a bet must be greater than zero.
+    #[display("stake is not positive")]
+    StakeNotPositive,
+
+    /// The stake of a bet must be a finite number.
+    #[display("stake is not finite")]
+    StakeNotFinite,
+}
+
+impl core::error::Error for BetError {}
+
+/// The fields of a bet as they are deserialized, before they are validated.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct BetFields {
+    odd: AnyOdd,
+    stake: f64,
+    #[serde(default)]
+    outcome: Outcome,
+    placed: Timestamp,
+    #[serde(default)]
+    settled: Option<Timestamp>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<BetFields> for Bet {
+    type Error = BetError;
+
+    fn try_from(fields: BetFields) -> Result<Self, Self::Error> {
+        let bet = Self::new(fields.odd, fields.stake, fields.placed)?;
+        Ok(Self {
+            outcome: fields.outcome,
+            settled: fields.settled,
+            ..bet
+        })
+    }
+}
@@ -1,3 +1,4 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(clippy::unwrap_used)]
 #![deny(unsafe_code)]
 #![deny(missing_docs)]
@@ -23,6 +24,18 @@
 //! // Moneyline odds
 //! let moneyline = Moneyline::new(-200).unwrap();
 //! ```
+//!
+//! Odds known ahead of time can be created with the [`odds!`] macro, which validates
+//! them at compile time:
+//!
+//! ```rust
+//! use wager::odd::{Decimal, Fractional, Moneyline};
+//! use wager::odds;
+//!
+//! const FRACTIONAL: Fractional = odds!(1/2);
+//! const DECIMAL: Decimal = odds!(1.5);
+//! const MONEYLINE: Moneyline = odds!(-200);
+//! ```
 //! ## Parse
 //!
 //! ```rust
@@ -39,6 +52,13 @@
 //!     AnyOdd::Decimal(decimal) => {} // Do something with decimal odd
 //!     AnyOdd::Moneyline(moneyline) => {} // Do something with moneyline odd
 //! }
+//!
+//! // Some inputs are valid in more than one format, e.g. "150" could be a
+//! // moneyline or a decimal odd. A hint can be given to choose between them:
+//! use wager::odd::{Format, FormatHint};
+//!
+//! assert!(AnyOdd::interpret("150").is_ambiguous());
+//! let decimal = AnyOdd::parse_with("150", FormatHint::Prefer(Format::Decimal)).unwrap();
 //! ```
 //!
 //! ## Convert
@@ -50,12 +70,48 @@
 //! let decimal = Decimal::try_from(fractional).unwrap();
 //! let moneyline = Moneyline::try_from(decimal).unwrap();
 //! ```
+//!
+//! Generic code can convert any [`Odd`](`odd::Odd`) without naming the source type:
+//!
+//! ```rust
+//! use wager::odd::{AnyOdd, Decimal, Odd};
+//!
+//! fn to_decimal(odd: impl Odd) -> Decimal {
+//!     odd.convert().unwrap()
+//! }
+//!
+//! let odd: AnyOdd = "-200".parse().unwrap();
+//! assert_eq!(to_decimal(odd), Decimal::new(1.5).unwrap());
+//! assert_eq!(odd.to_decimal().unwrap(), Decimal::new(1.5).unwrap());
+//! ```
 //! <div class="warning">
 //! It's very important to note that converting between odds is not always exact.
 //! For example, converting a decimal odd to a fractional odd requires the
 //! approximation of a real number from a rational number.
 //! </div>
 //!
+//! Use a [`Conversion`](`odd::Conversion`) to find out whether a conversion was exact,
+//! and how much it changed the price if not:
+//!
+//! ```rust
+//! use wager::odd::{Conversion, Decimal, Moneyline};
+//!
+//! let decimal = Decimal::new(1.91).unwrap();
+//! let conversion = Conversion::<Moneyline>::convert(decimal).unwrap();
+//! assert!(!conversion.is_exact());
+//! ```
+//!
+//! ## Display
+//!
+//! ```rust
+//! use wager::odd::{AnyOdd, Format};
+//!
+//! let odd: AnyOdd = "-110".parse().unwrap();
+//! assert_eq!(odd.to_string(), "-110");
+//! assert_eq!(odd.formatted().format(Format::Decimal).precision(2).to_string(), "1.91");
+//! assert_eq!(odd.formatted().probability().precision(1).to_string(), "52.4%");
+//! ```
+//!
 //! ## Calculate payout
 //!
 //! ```rust
@@ -86,6 +142,60 @@
 //! let b = AnyOdd::Moneyline(Moneyline::new(-200).unwrap());
 //! assert!(a < b);
 //! ```
+//!
+//! ## Calculate expected value
+//!
+//! ```rust
+//! use wager::odd::Decimal;
+//! use wager::value::{edge, expected_value};
+//!
+//! let decimal = Decimal::new(2.2).unwrap();
+//! assert!(expected_value(decimal, 0.5, 100.0) > 0.0);
+//! assert!(edge(decimal, 0.4) < 0.0);
+//! ```
+//!
+//! # Features
+//!
+//! - `std` (default): Use the standard library. Enables `alloc`.
+//! - `alloc`: Use an allocator without the rest of the standard library, for
+//!   [`Column`](`odd::Column`), [`Registry`](`odd::Registry`), the input of
+//!   [`OddError`](`odd::OddError`), and the [`market`] and [`ledger`] modules.
+//! - `exact`: Add `ExactOdd`, which represents odds exactly with integers of any size.
+//!   Enables `alloc`.
+//! - `libm`: Use [`libm`](https://docs.rs/libm) for float math, which `core` lacks.
+//!   This is required without `std`.
+//! - `serde`: Implement `Serialize` and `Deserialize`, and add the `serde` module of
+//!   alternative representations.
+//!
+//! Without `std`, the crate is `no_std`, e.g. for embedded targets:
+//!
+//! ```toml
+//! wager = { version = "0.1", default-features = false, features = ["libm"] }
+//! ```
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or `libm` feature must be enabled for float math");
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod buffer;
+pub mod clv;
+#[cfg(feature = "alloc")]
+pub mod ledger;
+#[doc(hidden)]
+pub mod macros;
+#[cfg(feature = "alloc")]
+pub mod market;
 mod math;
 pub mod odd;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(test)]
+mod testing;
+pub mod value;
+
+#[cfg(feature = "exact")]
+pub use num_bigint;
+#[cfg(feature = "exact")]
+pub use num_rational;
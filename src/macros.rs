@@ -0,0 +1,190 @@
+//! Support for the [`odds!`](crate::odds) macro.
+
+use crate::odd::{Format, Moneyline, OddError, OddErrorKind, Rule};
+
+/// Create an odd from a literal, validated at compile time.
+///
+/// - A fraction creates a [`Fractional`](crate::odd::Fractional), e.g. `odds!(5/2)`.
+/// - A signed number creates a [`Moneyline`](crate::odd::Moneyline), e.g. `odds!(+150)`
+///   or `odds!(-112.5)`.
+/// - An unsigned number creates a [`Decimal`](crate::odd::Decimal), e.g. `odds!(1.91)`.
+///
+/// Example
+/// ```rust
+/// use wager::odd::{Decimal, Fractional, Moneyline};
+/// use wager::odds;
+///
+/// const FAVOURITE: Moneyline = odds!(-150);
+///
+/// assert_eq!(odds!(5/2), Fractional::new(5, 2).unwrap());
+/// assert_eq!(odds!(+150), Moneyline::new(150).unwrap());
+/// assert_eq!(odds!(-112.5), Moneyline::from_parts(-1125, 1).unwrap());
+/// assert_eq!(odds!(1.91), Decimal::new(1.91).unwrap());
+/// assert_eq!(FAVOURITE.to_string(), "-150");
+/// ```
+///
+/// Invalid odds don't compile:
+/// ```rust,compile_fail
+/// let odd = wager::odds!(+50);
+/// ```
+/// ```rust,compile_fail
+/// let odd = wager::odds!(5/0);
+/// ```
+/// ```rust,compile_fail
+/// let odd = wager::odds!(0.5);
+/// ```
+#[macro_export]
+macro_rules! odds {
+    (+ $value:literal) => {
+        $crate::__expect!($crate::macros::moneyline(false, stringify!($value)))
+    };
+    (- $value:literal) => {
+        $crate::__expect!($crate::macros::moneyline(true, stringify!($value)))
+    };
+    ($numerator:literal / $denominator:literal) => {
+        $crate::__expect!($crate::odd::Fractional::new($numerator, $denominator))
+    };
+    ($value:literal) => {
+        $crate::__expect!($crate::odd::Decimal::new($value as f64))
+    };
+}
+
+/// Evaluate a result at compile time, failing to compile if it is an error.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expect {
+    ($result:expr) => {
+        const {
+            let result = $result;
+            let odd = match &result {
+                ::core::result::Result::Ok(odd) => *odd,
+                ::core::result::Result::Err(error) => $crate::macros::invalid(error.kind()),
+            };
+            // With `alloc` the error has a destructor, which can't run at compile time.
+            let _ = ::core::mem::ManuallyDrop::new(result);
+            odd
+        }
+    };
+}
+
+/// Create a moneyline odd from the digits of a literal, e.g. `112.5`.
+#[doc(hidden)]
+pub const fn moneyline(negative: bool, literal: &str) -> Result<Moneyline, OddError> {
+    let bytes = literal.as_bytes();
+    let (mut mantissa, mut places, mut point) = (0i64, 0u32, false);
+
+    let mut position = 0;
+    while position < bytes.len() {
+        match bytes[position] {
+            b'.' if !point && position > 0 && position + 1 < bytes.len() => point = true,
+            digit @ b'0'..=b'9' => {
+                let digit = (digit - b'0') as i64;
+                let Some(shifted) = mantissa.checked_mul(10) else {
+                    return Err(OddError::invalid(Format::Moneyline, Rule::OutOfRange));
+                };
+                // Accumulate negatively so that the full range of `i64` is reachable.
+                let Some(value) = shifted.checked_sub(digit) else {
+                    return Err(OddError::invalid(Format::Moneyline, Rule::OutOfRange));
+                };
+                mantissa = value;
+                places += point as u32;
+            }
+            _ => {
+                return Err(OddError::from_kind(
+                    Format::Moneyline,
+                    OddErrorKind::Parse { position },
+                ))
+            }
+        }
+        position += 1;
+    }
+
+    if !negative {
+        let Some(value) = mantissa.checked_neg() else {
+            return Err(OddError::invalid(Format::Moneyline, Rule::OutOfRange));
+        };
+        mantissa = value;
+    }
+
+    Moneyline::from_parts(mantissa, places)
+}
+
+/// Fail to compile with a message describing the error.
+#[doc(hidden)]
+pub const fn invalid(kind: OddErrorKind) -> ! {
+    match kind {
+        OddErrorKind::Parse { .. } => panic!("odds literal is not a number"),
+        OddErrorKind::Invalid(Rule::MoneylineBelow100) => {
+            panic!("invalid odds literal: moneyline magnitude below 100")
+        }
+        OddErrorKind::Invalid(Rule::DecimalBelowOne) => {
+            panic!("invalid odds literal: decimal below 1.0")
+        }
+        OddErrorKind::Invalid(Rule::DecimalNotFinite) => {
+            panic!("invalid odds literal: decimal not finite")
+        }
+        OddErrorKind::Invalid(Rule::ZeroNumerator) => {
+            panic!("invalid odds literal: numerator is zero")
+        }
+        OddErrorKind::Invalid(Rule::ZeroDenominator) => {
+            panic!("invalid odds literal: denominator is zero")
+        }
+        OddErrorKind::Invalid(Rule::OutOfRange) => {
+            panic!("invalid odds literal: value out of range")
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::odd::{AnyOdd, Decimal, Fractional};
+    use test_case::test_case;
+
+    #[test]
+    fn fractional() {
+        const ODD: Fractional = odds!(10 / 4);
+        assert_eq!(ODD, Fractional::new(5, 2).unwrap());
+        assert_eq!(odds!(1 / 1), Fractional::EVENS);
+    }
+
+    #[test]
+    fn decimal() {
+        const ODD: Decimal = odds!(1.91);
+        assert_eq!(ODD, Decimal::new(1.91).unwrap());
+        assert_eq!(odds!(2), Decimal::EVENS);
+    }
+
+    #[test]
+    fn moneyline() {
+        const ODD: Moneyline = odds!(-110);
+        assert_eq!(ODD, Moneyline::new(-110).unwrap());
+        assert_eq!(odds!(+250), Moneyline::new(250).unwrap());
+        assert_eq!(odds!(-100), Moneyline::EVENS);
+        assert_eq!(odds!(+112.50), Moneyline::from_parts(1125, 1).unwrap());
+        assert_eq!(AnyOdd::from(odds!(-112.5)).to_string(), "-112.5");
+    }
+
+    #[test_case(false, "150", (150, 0))]
+    #[test_case(true, "112.5", (-1125, 1))]
+    #[test_case(true, "9223372036854775808", (i64::MIN, 0))]
+    #[test_case(false, "9223372036854775807", (i64::MAX, 0))]
+    fn valid_moneyline(negative: bool, literal: &str, expected: (i64, u32)) {
+        let moneyline = super::moneyline(negative, literal).unwrap();
+        assert_eq!((moneyline.mantissa(), moneyline.places()), expected);
+    }
+
+    #[test_case(false, "99", OddErrorKind::Invalid(Rule::MoneylineBelow100))]
+    #[test_case(false, "9223372036854775808", OddErrorKind::Invalid(Rule::OutOfRange))]
+    #[test_case(true, "99999999999999999999", OddErrorKind::Invalid(Rule::OutOfRange))]
+    #[test_case(false, "150i64", OddErrorKind::Parse { position: 3 })]
+    #[test_case(false, "1_000", OddErrorKind::Parse { position: 1 })]
+    #[test_case(false, "112.", OddErrorKind::Parse { position: 3 })]
+    #[test_case(false, "1.1.1", OddErrorKind::Parse { position: 3 })]
+    fn invalid_moneyline(negative: bool, literal: &str, expected: OddErrorKind) {
+        let error = super::moneyline(negative, literal).unwrap_err();
+        assert_eq!(error.kind(), expected);
+        assert_eq!(error.format(), Format::Moneyline);
+    }
+}
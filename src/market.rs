@@ -0,0 +1,414 @@
+//! A model of what can be bet on and the prices offered for it.
+//!
+//! An [`Event`] has [markets](Market) of different [types](MarketType), each of which has
+//! [selections](Selection) for its possible outcomes. Bookmakers offer [prices](Price)
+//! for each selection over time.
+//!
+//! Requires the `alloc` feature. With the `serde` feature, every type can be
+//! (de)serialized.
+//!
+//! Example
+//! ```rust
+//! use wager::market::{Event, Market, MarketType, Price, Selection, Timestamp};
+//! use wager::odds;
+//!
+//! let kickoff = Timestamp::from_millis(1_700_000_000_000);
+//! let opened = Timestamp::from_millis(1_699_900_000_000);
+//!
+//! let mut event = Event::new("Arsenal v Chelsea", kickoff).with_market(
+//!     Market::new(MarketType::Handicap { line: -0.5 })
+//!         .with_selection(Selection::new("Arsenal"))
+//!         .with_selection(Selection::new("Chelsea")),
+//! );
+//!
+//! let market = event.market_mut(&MarketType::Handicap { line: -0.5 }).unwrap();
+//! let selection = market.selection_mut("Arsenal").unwrap();
+//! selection.add_price(Price::new(odds!(-120), "pinnacle", opened));
+//!
+//! let selection = &event.markets()[0].selections()[0];
+//! assert_eq!(selection.latest_price("pinnacle").unwrap().odd().to_string(), "-120");
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+use derive_more::Display;
+
+use crate::odd::Odd;
+
+mod consensus;
+mod event;
+mod price;
+mod selection;
+mod timestamp;
+
+pub use consensus::{Consensus, DeVig, FairPrice};
+pub use event::Event;
+pub use price::Price;
+pub use selection::Selection;
+pub use timestamp::Timestamp;
+
+/// The type of a market, which determines what its selections are.
+#[derive(Debug, Display, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum MarketType {
+    /// Which team wins, or a draw, i.e. home (1), draw (X) or away (2).
+    #[display("1X2")]
+    #[cfg_attr(feature = "serde", serde(rename = "1x2"))]
+    OneXTwo,
+    /// Which team wins, without a draw.
+    #[display("moneyline")]
+    Moneyline,
+    /// Whether a total, e.g. of goals or points, is over or under a line.
+    #[display("total {line}")]
+    Total {
+        /// The line that the total is compared to, e.g. `2.5`.
+        line: f64,
+    },
+    /// Which team wins after a handicap is applied to the score, e.g. `-1.5` for the
+    /// first selection.
+    #[display("handicap {line:+}")]
+    Handicap {
+        /// The handicap applied to the first selection.
+        line: f64,
+    },
+    /// Which of many competitors wins, e.g. a tournament or a race.
+    #[display("outright")]
+    Outright,
+    /// Any other type of market, by name.
+    #[display("{_0}")]
+    Other(String),
+}
+
+/// A market of an event, i.e. a question about its outcome with a [`Selection`] for
+/// each possible answer.
+///
+/// Example
+/// ```rust
+/// use wager::market::{Market, MarketType, Selection};
+///
+/// let market = Market::new(MarketType::Total { line: 2.5 })
+///     .with_selection(Selection::new("Over"))
+///     .with_selection(Selection::new("Under"));
+///
+/// assert_eq!(market.kind().to_string(), "total 2.5");
+/// assert_eq!(market.selection("Under").unwrap().name(), "Under");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Market {
+    kind: MarketType,
+    #[cfg_attr(feature = "serde", serde(default))]
+    selections: Vec<Selection>,
+}
+
+impl Market {
+    /// Create a new market without any selections.
+    pub fn new(kind: MarketType) -> Self {
+        Self {
+            kind,
+            selections: Vec::new(),
+        }
+    }
+
+    /// Add a selection, returning the market.
+    pub fn with_selection(mut self, selection: Selection) -> Self {
+        self.add_selection(selection);
+        self
+    }
+
+    /// Add a selection.
+    pub fn add_selection(&mut self, selection: Selection) {
+        self.selections.push(selection);
+    }
+
+    /// Get the type of the market.
+    pub fn kind(&self) -> &MarketType {
+        &self.kind
+    }
+
+    /// Get every selection, in the order they were added.
+    pub fn selections(&self) -> &[Selection] {
+        &self.selections
+    }
+
+    /// Get the first selection with a name.
+    pub fn selection(&self, name: &str) -> Option<&Selection> {
+        self.selections
+            .iter()
+            .find(|selection| selection.name() == name)
+    }
+
+    /// Get the first selection with a name, mutably.
+    pub fn selection_mut(&mut self, name: &str) -> Option<&mut Selection> {
+        self.selections
+            .iter_mut()
+            .find(|selection| selection.name() == name)
+    }
+
+    /// Get the best price currently offered for each selection, in the same order as the
+    /// selections. See [`Selection::best_price`].
+    pub fn best_prices(&self) -> impl Iterator<Item = (&Selection, Option<&Price>)> + '_ {
+        self.selections
+            .iter()
+            .map(|selection| (selection, selection.best_price()))
+    }
+
+    /// Get the overround of the market when every selection is backed at its best price,
+    /// i.e. the sum of their implied probabilities minus one.
+    ///
+    /// A negative overround means that backing every selection is an arbitrage. Returns
+    /// `None` if the market has no selections or any of them has no price.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::market::{Market, MarketType, Price, Selection, Timestamp};
+    /// use wager::odds;
+    ///
+    /// let now = Timestamp::EPOCH;
+    /// let market = Market::new(MarketType::Moneyline)
+    ///     .with_selection(
+    ///         Selection::new("Home")
+    ///             .with_price(Price::new(odds!(-110), "a", now))
+    ///             .with_price(Price::new(odds!(+105), "b", now)),
+    ///     )
+    ///     .with_selection(
+    ///         Selection::new("Away")
+    ///             .with_price(Price::new(odds!(-110), "a", now))
+    ///             .with_price(Price::new(odds!(-115), "b", now)),
+    ///     );
+    ///
+    /// // Home at +105 and away at -110.
+    /// let overround = market.best_overround().unwrap();
+    /// assert!((overround - (100.0 / 205.0 + 110.0 / 210.0 - 1.0)).abs() < 1e-12);
+    ///
+    /// // Bookmaker b has a lower margin than bookmaker a.
+    /// let ranking: Vec<&str> = market.margins().iter().map(|(name, _)| *name).collect();
+    /// assert_eq!(ranking, ["b", "a"]);
+    /// ```
+    pub fn best_overround(&self) -> Option<f64> {
+        if self.selections.is_empty() {
+            return None;
+        }
+
+        self.best_prices()
+            .map(|(_, price)| price.map(implied_probability))
+            .sum::<Option<f64>>()
+            .map(|book| book - 1.0)
+    }
+
+    /// Get the margin of each bookmaker that prices every selection, i.e. the overround
+    /// of its latest prices, ranked from the lowest margin to the highest. Ties are in
+    /// the order that the bookmakers were first seen.
+    pub fn margins(&self) -> Vec<(&str, f64)> {
+        let mut margins: Vec<(&str, f64)> = self
+            .bookmakers()
+            .into_iter()
+            .filter_map(|bookmaker| {
+                let book: f64 = self.implied_probabilities(bookmaker)?.iter().sum();
+                Some((bookmaker, book - 1.0))
+            })
+            .collect();
+
+        margins.sort_by(|a, b| a.1.total_cmp(&b.1));
+        margins
+    }
+
+    /// Get the probability implied by the latest price of a bookmaker for each
+    /// selection, or `None` if it doesn't price every selection.
+    fn implied_probabilities(&self, bookmaker: &str) -> Option<Vec<f64>> {
+        self.selections
+            .iter()
+            .map(|selection| selection.latest_price(bookmaker).map(implied_probability))
+            .collect()
+    }
+
+    /// Get the name of every bookmaker that has offered a price for any selection,
+    /// without duplicates, in the order that they were first seen.
+    pub fn bookmakers(&self) -> Vec<&str> {
+        let mut bookmakers: Vec<&str> = Vec::new();
+        for bookmaker in self.selections.iter().flat_map(Selection::bookmakers) {
+            if !bookmakers.contains(&bookmaker) {
+                bookmakers.push(bookmaker);
+            }
+        }
+
+        bookmakers
+    }
+}
+
+/// Get the probability implied by a price.
+fn implied_probability(price: &Price) -> f64 {
+    price.odd().payout(1.0).recip()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::odds;
+    use crate::testing::{assert_close, price};
+    use test_case::test_case;
+
+    #[test_case(MarketType::OneXTwo, "1X2")]
+    #[test_case(MarketType::Moneyline, "moneyline")]
+    #[test_case(MarketType::Total { line: 2.5 }, "total 2.5")]
+    #[test_case(MarketType::Handicap { line: -1.5 }, "handicap -1.5")]
+    #[test_case(MarketType::Handicap { line: 0.25 }, "handicap +0.25")]
+    #[test_case(MarketType::Outright, "outright")]
+    #[test_case(
+        MarketType::Other(String::from("first goalscorer")),
+        "first goalscorer"
+    )]
+    fn display_market_type(kind: MarketType, expected: &str) {
+        assert_eq!(kind.to_string(), expected);
+    }
+
+    #[test]
+    fn latest_prices() {
+        let selection = Selection::new("Draw")
+            .with_price(Price::new(odds!(+220), "a", Timestamp::from_millis(2)))
+            .with_price(Price::new(odds!(+210), "b", Timestamp::from_millis(1)))
+            .with_price(Price::new(odds!(+200), "a", Timestamp::from_millis(1)))
+            .with_price(Price::new(odds!(+230), "a", Timestamp::from_millis(2)));
+
+        let latest: Vec<String> = selection
+            .latest_prices()
+            .map(|price| price.odd().to_string())
+            .collect();
+
+        assert_eq!(latest, ["+230", "+210"]);
+        assert!(selection.latest_price("c").is_none());
+    }
+
+    #[test]
+    fn selection_mut() {
+        let mut market = Market::new(MarketType::Moneyline)
+            .with_selection(Selection::new("Home"))
+            .with_selection(Selection::new("Away"));
+
+        let price = Price::new(odds!(2.5), "a", Timestamp::EPOCH);
+        market
+            .selection_mut("Away")
+            .unwrap()
+            .add_price(price.clone());
+
+        assert!(market.selection("Home").unwrap().prices().is_empty());
+        assert_eq!(market.selection("Away").unwrap().prices(), [price]);
+        assert!(market.selection_mut("Draw").is_none());
+    }
+
+    #[test_case(&[("2.5", "a"), ("+150", "b"), ("3/2", "c")], "a"; "tie goes to first")]
+    #[test_case(&[("2.5", "a"), ("+155", "b"), ("3/2", "c")], "b"; "moneyline")]
+    #[test_case(&[("-200", "a"), ("1.5", "b"), ("11/20", "c")], "c"; "fractional")]
+    fn best_price(prices: &[(&str, &str)], expected: &str) {
+        let selection = prices
+            .iter()
+            .fold(Selection::new("Home"), |selection, (odd, bookmaker)| {
+                selection.with_price(price(odd, bookmaker, 0))
+            });
+
+        assert_eq!(selection.best_price().unwrap().bookmaker(), expected);
+    }
+
+    #[test]
+    fn best_price_latest() {
+        let selection = Selection::new("Home")
+            .with_price(price("3.0", "a", 0))
+            .with_price(price("2.0", "a", 1))
+            .with_price(price("1.9", "b", 0));
+
+        assert_eq!(selection.best_price().unwrap().odd().to_string(), "2.0");
+        assert!(Selection::new("Away").best_price().is_none());
+    }
+
+    fn market() -> Market {
+        Market::new(MarketType::OneXTwo)
+            .with_selection(
+                Selection::new("Home")
+                    .with_price(price("2.0", "a", 0))
+                    .with_price(price("+110", "b", 0))
+                    .with_price(price("6/5", "c", 0)),
+            )
+            .with_selection(
+                Selection::new("Draw")
+                    .with_price(price("3.0", "a", 0))
+                    .with_price(price("+210", "b", 0)),
+            )
+            .with_selection(
+                Selection::new("Away")
+                    .with_price(price("4.0", "a", 0))
+                    .with_price(price("+290", "b", 0))
+                    .with_price(price("4/1", "c", 0)),
+            )
+    }
+
+    #[test]
+    fn best_prices() {
+        let market = market();
+        let best: Vec<(&str, &str)> = market
+            .best_prices()
+            .map(|(selection, price)| (selection.name(), price.unwrap().bookmaker()))
+            .collect();
+
+        assert_eq!(best, [("Home", "c"), ("Draw", "b"), ("Away", "c")]);
+    }
+
+    #[test]
+    fn best_overround() {
+        // The best prices are 2.2, 3.1 and 5.0.
+        let expected = 1.0 / 2.2 + 1.0 / 3.1 + 1.0 / 5.0 - 1.0;
+        assert_close(market().best_overround().unwrap(), expected);
+
+        let mut market = market();
+        market.add_selection(Selection::new("Abandoned"));
+        assert_eq!(market.best_overround(), None);
+        assert_eq!(Market::new(MarketType::Outright).best_overround(), None);
+    }
+
+    #[test]
+    fn margins() {
+        let market = market();
+        let margins = market.margins();
+
+        // Bookmaker c doesn't price the draw.
+        assert_eq!(market.bookmakers(), ["a", "b", "c"]);
+        assert_eq!(margins.len(), 2);
+        assert_eq!(margins[0].0, "b");
+        assert_close(margins[0].1, 1.0 / 2.1 + 1.0 / 3.1 + 1.0 / 3.9 - 1.0);
+        assert_eq!(margins[1].0, "a");
+        assert_close(margins[1].1, 1.0 / 2.0 + 1.0 / 3.0 + 1.0 / 4.0 - 1.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let event = Event::new("Arsenal v Chelsea", Timestamp::from_millis(1000))
+            .with_market(Market::new(MarketType::OneXTwo).with_selection(
+                Selection::new("Draw").with_price(Price::new(
+                    odds!(5 / 2),
+                    "a",
+                    Timestamp::from_millis(10),
+                )),
+            ))
+            .with_market(Market::new(MarketType::Total { line: 2.5 }));
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            r#"{"name":"Arsenal v Chelsea","start":1000,"markets":[{"kind":{"type":"1x2"},"selections":[{"name":"Draw","prices":[{"odd":{"fractional":"5/2"},"bookmaker":"a","timestamp":10}]}]},{"kind":{"type":"total","line":2.5},"selections":[]}]}"#
+        );
+        assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+
+        let json = r#"{"name":"Outright","start":0,"markets":[{"kind":{"type":"outright"}}]}"#;
+        let event: Event = serde_json::from_str(json).unwrap();
+        assert!(event.markets()[0].selections().is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_invalid_price() {
+        let json = r#"{"odd":{"decimal":0.5},"bookmaker":"a","timestamp":0}"#;
+        assert!(serde_json::from_str::<Price>(json).is_err());
+    }
+}
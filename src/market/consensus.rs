@@ -0,0 +1,378 @@
+use alloc::{string::String, vec::Vec};
+
+use super::Market;
+use crate::math::{powf, sqrt};
+use crate::odd::{Decimal, OddError};
+
+/// A method of removing a bookmaker's margin from the probabilities implied by its
+/// prices, so that they sum to one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DeVig {
+    /// Divide each probability by their sum, i.e. remove the margin in proportion to
+    /// each probability.
+    #[default]
+    Multiplicative,
+    /// Subtract an equal share of the margin from each probability. Long prices can be
+    /// left with a negative probability.
+    Additive,
+    /// Raise each probability to the power that makes them sum to one, which removes
+    /// more of the margin from long prices to account for the favourite-longshot bias.
+    Power,
+}
+
+impl DeVig {
+    /// Remove the margin from implied probabilities.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::market::DeVig;
+    ///
+    /// let fair = DeVig::Multiplicative.apply(&[0.5, 0.3, 0.25]);
+    /// assert_eq!(fair, [0.5 / 1.05, 0.3 / 1.05, 0.25 / 1.05]);
+    ///
+    /// let fair = DeVig::Additive.apply(&[0.55, 0.5]);
+    /// assert!((fair[0] - 0.525).abs() < 1e-12 && (fair[1] - 0.475).abs() < 1e-12);
+    /// ```
+    pub fn apply(self, probabilities: &[f64]) -> Vec<f64> {
+        let book: f64 = probabilities.iter().sum();
+
+        match self {
+            DeVig::Multiplicative => probabilities.iter().map(|p| p / book).collect(),
+            DeVig::Additive => {
+                let share = (book - 1.0) / probabilities.len() as f64;
+                probabilities.iter().map(|p| p - share).collect()
+            }
+            DeVig::Power => {
+                let exponent = power_exponent(probabilities);
+                probabilities.iter().map(|p| powf(*p, exponent)).collect()
+            }
+        }
+    }
+}
+
+/// Find the exponent that the probabilities must be raised to for them to sum to one,
+/// by bisection.
+fn power_exponent(probabilities: &[f64]) -> f64 {
+    let book = |exponent: f64| -> f64 { probabilities.iter().map(|p| powf(*p, exponent)).sum() };
+
+    // The sum decreases as the exponent increases, from the number of probabilities at
+    // zero, so the root lies between zero and the first power of two that undershoots.
+    let (mut low, mut high) = (0.0, 1.0);
+    while book(high) > 1.0 && high < f64::MAX {
+        low = high;
+        high *= 2.0;
+    }
+
+    for _ in 0..100 {
+        let middle = (low + high) / 2.0;
+        if book(middle) > 1.0 {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    (low + high) / 2.0
+}
+
+/// A consensus of bookmakers' fair prices for every selection of a market, to use as a
+/// reference line, e.g. for detecting value.
+///
+/// Each bookmaker that prices every selection has the margin removed from its latest
+/// prices with a [`DeVig`] method, and the resulting fair probabilities are averaged
+/// with a weight per bookmaker, e.g. to trust sharp bookmakers more. Bookmakers have a
+/// weight of one unless configured otherwise, and a weight of zero excludes them.
+///
+/// Example
+/// ```rust
+/// use wager::market::{Consensus, DeVig, Market, MarketType, Price, Selection, Timestamp};
+/// use wager::odds;
+///
+/// let now = Timestamp::from_millis(0);
+/// let market = Market::new(MarketType::Moneyline)
+///     .with_selection(
+///         Selection::new("Home")
+///             .with_price(Price::new(odds!(-110), "pinnacle", now))
+///             .with_price(Price::new(odds!(-120), "soft", now)),
+///     )
+///     .with_selection(
+///         Selection::new("Away")
+///             .with_price(Price::new(odds!(-110), "pinnacle", now))
+///             .with_price(Price::new(odds!(+100), "soft", now)),
+///     );
+///
+/// let consensus = Consensus::new()
+///     .with_method(DeVig::Multiplicative)
+///     .with_weight("pinnacle", 3.0);
+/// let fair = consensus.fair_prices(&market).unwrap();
+///
+/// let (name, home) = fair[0];
+/// assert_eq!(name, "Home");
+/// assert_eq!(home.bookmakers(), 2);
+/// assert!(home.probability() > 0.5 && home.probability() < home.max());
+/// assert!(home.odd().unwrap().value() < 2.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Consensus {
+    method: DeVig,
+    weights: Vec<(String, f64)>,
+}
+
+impl Consensus {
+    /// Create a consensus that removes margins multiplicatively and weighs every
+    /// bookmaker equally.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the method of removing each bookmaker's margin.
+    pub fn with_method(mut self, method: DeVig) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Set the weight of a bookmaker, replacing any previous weight.
+    pub fn with_weight(mut self, bookmaker: impl Into<String>, weight: f64) -> Self {
+        self.set_weight(bookmaker, weight);
+        self
+    }
+
+    /// Set the weight of a bookmaker, replacing any previous weight.
+    pub fn set_weight(&mut self, bookmaker: impl Into<String>, weight: f64) {
+        let bookmaker = bookmaker.into();
+        match self.weights.iter_mut().find(|(name, _)| *name == bookmaker) {
+            Some((_, previous)) => *previous = weight,
+            None => self.weights.push((bookmaker, weight)),
+        }
+    }
+
+    /// Get the method of removing each bookmaker's margin.
+    pub fn method(&self) -> DeVig {
+        self.method
+    }
+
+    /// Get the weight of a bookmaker.
+    pub fn weight(&self, bookmaker: &str) -> f64 {
+        self.weights
+            .iter()
+            .find(|(name, _)| name == bookmaker)
+            .map_or(1.0, |(_, weight)| *weight)
+    }
+
+    /// Get the fair price of each selection of a market, in the order of the
+    /// selections.
+    ///
+    /// Returns `None` if no bookmaker with a positive weight prices every selection.
+    pub fn fair_prices<'a>(&self, market: &'a Market) -> Option<Vec<(&'a str, FairPrice)>> {
+        let books: Vec<(f64, Vec<f64>)> = market
+            .bookmakers()
+            .into_iter()
+            .filter(|bookmaker| self.weight(bookmaker) > 0.0)
+            .filter_map(|bookmaker| {
+                let probabilities = market.implied_probabilities(bookmaker)?;
+                Some((self.weight(bookmaker), self.method.apply(&probabilities)))
+            })
+            .collect();
+
+        if books.is_empty() || market.selections().is_empty() {
+            return None;
+        }
+
+        let total: f64 = books.iter().map(|(weight, _)| weight).sum();
+
+        let fair_prices = market
+            .selections()
+            .iter()
+            .enumerate()
+            .map(|(index, selection)| {
+                let fair = books.iter().map(|(weight, book)| (*weight, book[index]));
+
+                let probability = fair.clone().map(|(w, p)| w * p).sum::<f64>() / total;
+                let variance = fair
+                    .clone()
+                    .map(|(w, p)| w * (p - probability) * (p - probability))
+                    .sum::<f64>()
+                    / total;
+
+                let fair_price = FairPrice {
+                    probability,
+                    std_dev: sqrt(variance),
+                    min: fair.clone().map(|(_, p)| p).fold(f64::INFINITY, f64::min),
+                    max: fair.map(|(_, p)| p).fold(f64::NEG_INFINITY, f64::max),
+                    bookmakers: books.len(),
+                };
+
+                (selection.name(), fair_price)
+            })
+            .collect();
+
+        Some(fair_prices)
+    }
+}
+
+/// The consensus fair price of a selection, and how much the bookmakers' fair
+/// probabilities disagree about it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FairPrice {
+    probability: f64,
+    std_dev: f64,
+    min: f64,
+    max: f64,
+    bookmakers: usize,
+}
+
+impl FairPrice {
+    /// Get the weighted mean of the bookmakers' fair probabilities.
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+
+    /// Get the fair odd, i.e. the inverse of the fair probability.
+    ///
+    /// This will error if the fair probability isn't positive, which is possible with
+    /// [`DeVig::Additive`].
+    pub fn odd(&self) -> Result<Decimal, OddError> {
+        Decimal::new(self.probability.recip())
+    }
+
+    /// Get the weighted standard deviation of the bookmakers' fair probabilities.
+    pub fn std_dev(&self) -> f64 {
+        self.std_dev
+    }
+
+    /// Get the lowest of the bookmakers' fair probabilities.
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Get the highest of the bookmakers' fair probabilities.
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Get the number of bookmakers that the consensus is of.
+    pub fn bookmakers(&self) -> usize {
+        self.bookmakers
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::market::{MarketType, Selection};
+    use crate::testing::{assert_close, price};
+    use test_case::test_case;
+
+    fn market(books: &[(&str, [&str; 2])]) -> Market {
+        let mut market = Market::new(MarketType::Moneyline)
+            .with_selection(Selection::new("Home"))
+            .with_selection(Selection::new("Away"));
+
+        for (bookmaker, odds) in books {
+            for (name, odd) in ["Home", "Away"].iter().zip(odds) {
+                let price = price(odd, bookmaker, 0);
+                market.selection_mut(name).unwrap().add_price(price);
+            }
+        }
+
+        market
+    }
+
+    #[test_case(DeVig::Multiplicative, &[0.6, 0.5], &[0.6 / 1.1, 0.5 / 1.1])]
+    #[test_case(DeVig::Additive, &[0.6, 0.5], &[0.55, 0.45])]
+    #[test_case(DeVig::Additive, &[0.98, 0.1], &[0.94, 0.06])]
+    #[test_case(DeVig::Power, &[0.5, 0.5], &[0.5, 0.5])]
+    #[test_case(DeVig::Power, &[0.4, 0.4], &[0.5, 0.5])]
+    fn apply(method: DeVig, probabilities: &[f64], expected: &[f64]) {
+        let fair = method.apply(probabilities);
+        assert_eq!(fair.len(), expected.len());
+        for (fair, expected) in fair.iter().zip(expected) {
+            assert_close(*fair, *expected);
+        }
+    }
+
+    #[test_case(&[0.8, 0.3])]
+    #[test_case(&[0.5, 0.3, 0.15, 0.08, 0.02])]
+    #[test_case(&[0.5, 0.35, 0.2])]
+    fn power_sums_to_one(probabilities: &[f64]) {
+        let fair = DeVig::Power.apply(probabilities);
+        assert_close(fair.iter().sum(), 1.0);
+
+        // The longest price loses a larger share of its probability than the shortest.
+        let multiplicative = DeVig::Multiplicative.apply(probabilities);
+        assert!(fair[0] > multiplicative[0]);
+        assert!(fair[fair.len() - 1] < multiplicative[fair.len() - 1]);
+    }
+
+    #[test]
+    fn fair_prices() {
+        let market = market(&[("a", ["1.8", "2.0"]), ("b", ["2.0", "1.8"])]);
+        let fair = Consensus::new().fair_prices(&market).unwrap();
+
+        assert_eq!(fair.len(), 2);
+        assert_eq!(fair[0].0, "Home");
+        assert_eq!(fair[1].0, "Away");
+
+        let home = fair[0].1;
+        let (high, low) = (2.0 / 3.8, 1.8 / 3.8);
+        assert_close(home.probability(), 0.5);
+        assert_close(home.odd().unwrap().value(), 2.0);
+        assert_close(home.std_dev(), high - 0.5);
+        assert_close(home.min(), low);
+        assert_close(home.max(), high);
+        assert_eq!(home.bookmakers(), 2);
+    }
+
+    #[test]
+    fn fair_prices_weighted() {
+        let market = market(&[("a", ["1.8", "2.0"]), ("b", ["2.0", "1.8"])]);
+        let consensus = Consensus::new().with_weight("a", 3.0);
+        let home = consensus.fair_prices(&market).unwrap()[0].1;
+
+        let expected = (3.0 * 2.0 / 3.8 + 1.8 / 3.8) / 4.0;
+        assert_close(home.probability(), expected);
+
+        let consensus = consensus.with_weight("a", 0.0);
+        let home = consensus.fair_prices(&market).unwrap()[0].1;
+        assert_close(home.probability(), 1.8 / 3.8);
+        assert_eq!(home.std_dev(), 0.0);
+        assert_eq!(home.bookmakers(), 1);
+    }
+
+    #[test]
+    fn fair_prices_partial() {
+        let mut market = market(&[("a", ["2.0", "2.0"])]);
+        market
+            .selection_mut("Home")
+            .unwrap()
+            .add_price(price("1.5", "b", 0));
+
+        // Bookmaker b doesn't price every selection, so it is left out.
+        let fair = Consensus::new().fair_prices(&market).unwrap();
+        assert_eq!(fair[0].1.bookmakers(), 1);
+        assert_eq!(fair[0].1.probability(), 0.5);
+
+        let consensus = Consensus::new().with_weight("a", 0.0);
+        assert_eq!(consensus.fair_prices(&market), None);
+        assert_eq!(
+            Consensus::new().fair_prices(&Market::new(MarketType::Outright)),
+            None
+        );
+    }
+
+    #[test]
+    fn weights() {
+        let mut consensus = Consensus::new().with_weight("a", 2.0);
+        consensus.set_weight("a", 4.0);
+
+        assert_eq!(consensus.weight("a"), 4.0);
+        assert_eq!(consensus.weight("b"), 1.0);
+        assert_eq!(consensus.method(), DeVig::Multiplicative);
+    }
+}
@@ -0,0 +1,78 @@
+use alloc::{string::String, vec::Vec};
+
+use super::{Market, MarketType, Timestamp};
+
+/// A sporting event, or anything else that can be bet on, and its markets.
+///
+/// Example
+/// ```rust
+/// use wager::market::{Event, Market, MarketType, Selection, Timestamp};
+///
+/// let event = Event::new("Arsenal v Chelsea", Timestamp::from_millis(1_700_000_000_000))
+///     .with_market(
+///         Market::new(MarketType::OneXTwo)
+///             .with_selection(Selection::new("Arsenal"))
+///             .with_selection(Selection::new("Draw"))
+///             .with_selection(Selection::new("Chelsea")),
+///     )
+///     .with_market(Market::new(MarketType::Total { line: 2.5 }));
+///
+/// assert_eq!(event.markets().len(), 2);
+/// assert!(event.market(&MarketType::Total { line: 2.5 }).is_some());
+/// assert!(event.market(&MarketType::Total { line: 3.5 }).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Event {
+    name: String,
+    start: Timestamp,
+    #[cfg_attr(feature = "serde", serde(default))]
+    markets: Vec<Market>,
+}
+
+impl Event {
+    /// Create a new event without any markets.
+    pub fn new(name: impl Into<String>, start: Timestamp) -> Self {
+        Self {
+            name: name.into(),
+            start,
+            markets: Vec::new(),
+        }
+    }
+
+    /// Add a market, returning the event.
+    pub fn with_market(mut self, market: Market) -> Self {
+        self.add_market(market);
+        self
+    }
+
+    /// Add a market.
+    pub fn add_market(&mut self, market: Market) {
+        self.markets.push(market);
+    }
+
+    /// Get the name of the event.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the time at which the event starts.
+    pub fn start(&self) -> Timestamp {
+        self.start
+    }
+
+    /// Get every market, in the order they were added.
+    pub fn markets(&self) -> &[Market] {
+        &self.markets
+    }
+
+    /// Get the first market of a type.
+    pub fn market(&self, kind: &MarketType) -> Option<&Market> {
+        self.markets.iter().find(|market| market.kind() == kind)
+    }
+
+    /// Get the first market of a type, mutably.
+    pub fn market_mut(&mut self, kind: &MarketType) -> Option<&mut Market> {
+        self.markets.iter_mut().find(|market| market.kind() == kind)
+    }
+}
@@ -0,0 +1,49 @@
+use alloc::string::String;
+
+use super::Timestamp;
+use crate::odd::AnyOdd;
+
+/// An odd offered by a bookmaker at a point in time.
+///
+/// Example
+/// ```rust
+/// use wager::market::{Price, Timestamp};
+/// use wager::odds;
+///
+/// let price = Price::new(odds!(-110), "pinnacle", Timestamp::from_millis(0));
+/// assert_eq!(price.odd().to_string(), "-110");
+/// assert_eq!(price.bookmaker(), "pinnacle");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Price {
+    odd: AnyOdd,
+    bookmaker: String,
+    timestamp: Timestamp,
+}
+
+impl Price {
+    /// Create a new price.
+    pub fn new(odd: impl Into<AnyOdd>, bookmaker: impl Into<String>, timestamp: Timestamp) -> Self {
+        Self {
+            odd: odd.into(),
+            bookmaker: bookmaker.into(),
+            timestamp,
+        }
+    }
+
+    /// Get the odd that was offered.
+    pub fn odd(&self) -> AnyOdd {
+        self.odd
+    }
+
+    /// Get the name of the bookmaker that offered the odd.
+    pub fn bookmaker(&self) -> &str {
+        &self.bookmaker
+    }
+
+    /// Get the time at which the odd was offered.
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
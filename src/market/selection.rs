@@ -0,0 +1,116 @@
+use alloc::{string::String, vec::Vec};
+
+use super::Price;
+
+/// A possible outcome of a market, e.g. the home team winning, along with the prices
+/// offered for it.
+///
+/// Example
+/// ```rust
+/// use wager::market::{Price, Selection, Timestamp};
+/// use wager::odds;
+///
+/// let mut selection = Selection::new("Over");
+/// selection.add_price(Price::new(odds!(1.9), "a", Timestamp::from_millis(1)));
+/// selection.add_price(Price::new(odds!(1.8), "a", Timestamp::from_millis(2)));
+/// selection.add_price(Price::new(odds!(2.0), "b", Timestamp::from_millis(1)));
+///
+/// assert_eq!(selection.prices().len(), 3);
+/// assert_eq!(selection.latest_price("a").unwrap().odd().to_string(), "1.8");
+/// assert_eq!(selection.bookmakers().collect::<Vec<_>>(), ["a", "b"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Selection {
+    name: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    prices: Vec<Price>,
+}
+
+impl Selection {
+    /// Create a new selection without any prices.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            prices: Vec::new(),
+        }
+    }
+
+    /// Add a price, returning the selection.
+    pub fn with_price(mut self, price: Price) -> Self {
+        self.add_price(price);
+        self
+    }
+
+    /// Add a price.
+    pub fn add_price(&mut self, price: Price) {
+        self.prices.push(price);
+    }
+
+    /// Get the name of the selection.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get every price, in the order they were added.
+    pub fn prices(&self) -> &[Price] {
+        &self.prices
+    }
+
+    /// Get the most recent price of each bookmaker, in the order that the bookmakers were
+    /// first seen.
+    pub fn latest_prices(&self) -> impl Iterator<Item = &Price> + '_ {
+        self.bookmakers()
+            .filter_map(|bookmaker| self.latest_price(bookmaker))
+    }
+
+    /// Get the most recent price of a bookmaker. Of prices with the same timestamp, the
+    /// last one added is the most recent.
+    pub fn latest_price(&self, bookmaker: &str) -> Option<&Price> {
+        self.prices
+            .iter()
+            .filter(|price| price.bookmaker() == bookmaker)
+            .max_by_key(|price| price.timestamp())
+    }
+
+    /// Get the best price currently offered, i.e. the latest price of each bookmaker with
+    /// the highest payout. Odds of different formats are compared exactly, and ties go
+    /// to the bookmaker that was seen first.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::market::{Price, Selection, Timestamp};
+    /// use wager::odds;
+    ///
+    /// let selection = Selection::new("Home")
+    ///     .with_price(Price::new(odds!(-110), "a", Timestamp::from_millis(1)))
+    ///     .with_price(Price::new(odds!(1.95), "b", Timestamp::from_millis(1)))
+    ///     .with_price(Price::new(odds!(10 / 11), "c", Timestamp::from_millis(1)))
+    ///     .with_price(Price::new(odds!(2.1), "a", Timestamp::from_millis(0)));
+    ///
+    /// assert_eq!(selection.best_price().unwrap().bookmaker(), "b");
+    /// ```
+    pub fn best_price(&self) -> Option<&Price> {
+        self.latest_prices().reduce(|best, price| {
+            if price.odd() > best.odd() {
+                price
+            } else {
+                best
+            }
+        })
+    }
+
+    /// Get the name of every bookmaker that has offered a price, without duplicates, in
+    /// the order that they were first seen.
+    pub fn bookmakers(&self) -> impl Iterator<Item = &str> + '_ {
+        self.prices
+            .iter()
+            .enumerate()
+            .filter(|(index, price)| {
+                !self.prices[..*index]
+                    .iter()
+                    .any(|earlier| earlier.bookmaker() == price.bookmaker())
+            })
+            .map(|(_, price)| price.bookmaker())
+    }
+}
@@ -0,0 +1,129 @@
+use core::time::Duration;
+
+/// A point in time, as the number of milliseconds since the Unix epoch.
+///
+/// Example
+/// ```rust
+/// use std::time::Duration;
+/// use wager::market::Timestamp;
+///
+/// let timestamp = Timestamp::from_millis(1_700_000_000_000);
+/// assert_eq!(timestamp + Duration::from_secs(1), Timestamp::from_millis(1_700_000_001_000));
+/// assert!(timestamp < Timestamp::from_millis(1_700_000_000_001));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Timestamp {
+    millis: u64,
+}
+
+impl Timestamp {
+    /// The Unix epoch.
+    pub const EPOCH: Self = Self::from_millis(0);
+
+    /// Create a timestamp from the number of milliseconds since the Unix epoch.
+    pub const fn from_millis(millis: u64) -> Self {
+        Self { millis }
+    }
+
+    /// Get the current time.
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        std::time::SystemTime::now().into()
+    }
+
+    /// Get the number of milliseconds since the Unix epoch.
+    pub const fn as_millis(&self) -> u64 {
+        self.millis
+    }
+
+    /// Get the time elapsed since an earlier timestamp, or `None` if it is later.
+    pub fn duration_since(&self, earlier: Timestamp) -> Option<Duration> {
+        self.millis
+            .checked_sub(earlier.millis)
+            .map(Duration::from_millis)
+    }
+}
+
+impl core::ops::Add<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    /// Add a duration, saturating at the latest representable time.
+    fn add(self, rhs: Duration) -> Self::Output {
+        let millis = u64::try_from(rhs.as_millis()).unwrap_or(u64::MAX);
+        Self::from_millis(self.millis.saturating_add(millis))
+    }
+}
+
+impl core::ops::Sub<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    /// Subtract a duration, saturating at the Unix epoch.
+    fn sub(self, rhs: Duration) -> Self::Output {
+        let millis = u64::try_from(rhs.as_millis()).unwrap_or(u64::MAX);
+        Self::from_millis(self.millis.saturating_sub(millis))
+    }
+}
+
+/// Times before the Unix epoch saturate to it.
+#[cfg(feature = "std")]
+impl From<std::time::SystemTime> for Timestamp {
+    fn from(value: std::time::SystemTime) -> Self {
+        let since_epoch = value
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default();
+
+        Self::EPOCH + since_epoch
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Timestamp> for std::time::SystemTime {
+    fn from(value: Timestamp) -> Self {
+        std::time::UNIX_EPOCH + Duration::from_millis(value.millis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use test_case::test_case;
+
+    #[test_case(1_000, 400, Some(600))]
+    #[test_case(1_000, 1_000, Some(0))]
+    #[test_case(400, 1_000, None)]
+    fn duration_since(later: u64, earlier: u64, expected: Option<u64>) {
+        let duration =
+            Timestamp::from_millis(later).duration_since(Timestamp::from_millis(earlier));
+        assert_eq!(duration, expected.map(Duration::from_millis));
+    }
+
+    #[test]
+    fn saturating() {
+        assert_eq!(
+            Timestamp::from_millis(u64::MAX - 1) + Duration::from_secs(1),
+            Timestamp::from_millis(u64::MAX)
+        );
+        assert_eq!(
+            Timestamp::from_millis(1) - Duration::from_secs(1),
+            Timestamp::EPOCH
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn system_time() {
+        let timestamp = Timestamp::from_millis(1_700_000_000_123);
+        let system_time = SystemTime::from(timestamp);
+
+        assert_eq!(Timestamp::from(system_time), timestamp);
+        assert_eq!(
+            Timestamp::from(UNIX_EPOCH - Duration::from_secs(1)),
+            Timestamp::EPOCH
+        );
+        assert!(Timestamp::now() > timestamp);
+    }
+}
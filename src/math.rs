@@ -1,20 +1,28 @@
+mod float;
+mod ratio;
 mod rational_approximation;
 
+#[cfg(feature = "alloc")]
+pub use float::{exp, powf, sqrt};
+pub use float::{floor, fract, powi, round};
+#[cfg(feature = "exact")]
+pub use ratio::decimal_digits;
+pub use ratio::Ratio;
 pub use rational_approximation::rational_approximation;
 
-pub fn simplify_fraction(numerator: u32, denominator: u32) -> (u32, u32) {
+pub const fn simplify_fraction(numerator: u64, denominator: u64) -> (u64, u64) {
     if numerator == 0 || denominator == 0 {
         return (0, 0);
     }
 
-    let gcd = gcd(numerator, denominator);
+    let gcd = gcd(numerator as u128, denominator as u128) as u64;
     let numerator = numerator / gcd;
     let denominator = denominator / gcd;
 
     (numerator, denominator)
 }
 
-pub fn gcd(a: u32, b: u32) -> u32 {
+pub const fn gcd(a: u128, b: u128) -> u128 {
     if b == 0 {
         a
     } else {
@@ -22,10 +30,6 @@ pub fn gcd(a: u32, b: u32) -> u32 {
     }
 }
 
-pub fn lcm(a: u32, b: u32) -> u32 {
-    (a * b) / gcd(a, b)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -33,7 +37,9 @@ mod tests {
     #[test_case(1, 2, (1, 2))]
     #[test_case(10, 20, (1, 2))]
     #[test_case(46, 23, (2, 1))]
-    fn simplify_test(numerator: u32, denominator: u32, expected: (u32, u32)) {
+    #[test_case(u64::MAX, u64::MAX, (1, 1))]
+    #[test_case(u64::MAX - 1, 2, (u64::MAX / 2, 1))]
+    fn simplify_test(numerator: u64, denominator: u64, expected: (u64, u64)) {
         let result = simplify_fraction(numerator, denominator);
         assert_eq!(result, expected);
     }
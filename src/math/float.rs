@@ -0,0 +1,73 @@
+//! Float functions that `core` lacks, from `std` or otherwise `libm`.
+
+#[cfg(feature = "std")]
+pub fn floor(value: f64) -> f64 {
+    value.floor()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn floor(value: f64) -> f64 {
+    libm::floor(value)
+}
+
+/// Round half away from zero.
+#[cfg(feature = "std")]
+pub fn round(value: f64) -> f64 {
+    value.round()
+}
+
+/// Round half away from zero.
+#[cfg(not(feature = "std"))]
+pub fn round(value: f64) -> f64 {
+    libm::round(value)
+}
+
+#[cfg(feature = "std")]
+pub fn fract(value: f64) -> f64 {
+    value.fract()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn fract(value: f64) -> f64 {
+    value - libm::trunc(value)
+}
+
+#[cfg(feature = "std")]
+pub fn powi(value: f64, exponent: i32) -> f64 {
+    value.powi(exponent)
+}
+
+#[cfg(not(feature = "std"))]
+pub fn powi(value: f64, exponent: i32) -> f64 {
+    libm::pow(value, f64::from(exponent))
+}
+
+#[cfg(feature = "std")]
+pub fn powf(value: f64, exponent: f64) -> f64 {
+    value.powf(exponent)
+}
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+pub fn powf(value: f64, exponent: f64) -> f64 {
+    libm::pow(value, exponent)
+}
+
+#[cfg(feature = "std")]
+pub fn sqrt(value: f64) -> f64 {
+    value.sqrt()
+}
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+pub fn sqrt(value: f64) -> f64 {
+    libm::sqrt(value)
+}
+
+#[cfg(feature = "std")]
+pub fn exp(value: f64) -> f64 {
+    value.exp()
+}
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+pub fn exp(value: f64) -> f64 {
+    libm::exp(value)
+}
@@ -0,0 +1,178 @@
+use core::cmp::Ordering;
+
+use super::gcd;
+use crate::buffer::Buffer;
+
+/// An exact, non-negative rational number in its lowest terms.
+///
+/// Every odd can be represented exactly as the ratio of its total payout to its stake,
+/// which makes this a canonical form for comparing and hashing odds of different types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio {
+    numerator: u128,
+    denominator: u128,
+}
+
+impl Ratio {
+    /// Create a new ratio, reducing it to its lowest terms.
+    ///
+    /// The denominator must not be zero.
+    pub const fn new(numerator: u128, denominator: u128) -> Self {
+        debug_assert!(denominator != 0);
+
+        let gcd = gcd(numerator, denominator);
+
+        Self {
+            numerator: numerator / gcd,
+            denominator: denominator / gcd,
+        }
+    }
+
+    /// Get the numerator of the ratio.
+    #[cfg_attr(not(feature = "exact"), allow(dead_code))]
+    pub const fn numerator(&self) -> u128 {
+        self.numerator
+    }
+
+    /// Get the denominator of the ratio.
+    #[cfg_attr(not(feature = "exact"), allow(dead_code))]
+    pub const fn denominator(&self) -> u128 {
+        self.denominator
+    }
+
+    /// Create the ratio of the shortest decimal that a finite, non-negative float is
+    /// displayed as, e.g. 11/10 for `1.1` rather than the float's exact binary value.
+    ///
+    /// Distinct floats are displayed as distinct decimals, so this is still exact in
+    /// the sense that it identifies the float, and it matches the value that was typed.
+    ///
+    /// Returns `None` if the value is negative, not finite, or too large to be
+    /// represented.
+    pub fn from_f64(value: f64) -> Option<Self> {
+        let (digits, exponent) = decimal_digits(value)?;
+        let scale = 10u128.checked_pow(exponent.unsigned_abs())?;
+
+        if exponent >= 0 {
+            Some(Self::new(digits.checked_mul(scale)?, 1))
+        } else {
+            Some(Self::new(digits, scale))
+        }
+    }
+}
+
+/// Get the significant digits and the power of ten of the shortest decimal that a
+/// finite, non-negative float is displayed as, e.g. `(191, -2)` for `1.91`.
+pub fn decimal_digits(value: f64) -> Option<(u128, i32)> {
+    if !value.is_finite() || value.is_sign_negative() {
+        return None;
+    }
+
+    // Scientific notation is the shortest that round trips, e.g. `1.91e0`.
+    let buffer = Buffer::<32>::format(format_args!("{value:e}"))?;
+    let (mantissa, exponent) = buffer.as_str().split_once('e')?;
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let digits = whole
+        .bytes()
+        .chain(fraction.bytes())
+        .try_fold(0u128, |digits, digit| {
+            digits
+                .checked_mul(10)?
+                .checked_add(u128::from(digit.checked_sub(b'0')?))
+        })?;
+    let exponent = exponent.parse::<i32>().ok()? - i32::try_from(fraction.len()).ok()?;
+
+    Some((digits, exponent))
+}
+
+impl PartialOrd for Ratio {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ratio {
+    fn cmp(&self, other: &Self) -> Ordering {
+        widening_mul(self.numerator, other.denominator)
+            .cmp(&widening_mul(other.numerator, self.denominator))
+    }
+}
+
+/// Multiply two `u128` values, returning the full 256-bit product as `(high, low)`.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let (a_high, a_low) = (a >> 64, a & MASK);
+    let (b_high, b_low) = (b >> 64, b & MASK);
+
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+
+    let middle = (low_low >> 64) + (low_high & MASK) + (high_low & MASK);
+
+    let low = (low_low & MASK) | (middle << 64);
+    let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+
+    (high, low)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case((2, 4), (1, 2))]
+    #[test_case((0, 5), (0, 1))]
+    #[test_case((250, 100), (5, 2))]
+    fn new(value: (u128, u128), expected: (u128, u128)) {
+        let ratio = Ratio::new(value.0, value.1);
+        assert_eq!((ratio.numerator, ratio.denominator), expected);
+    }
+
+    #[test_case(1.5, (3, 2))]
+    #[test_case(2.0, (2, 1))]
+    #[test_case(1.125, (9, 8))]
+    #[test_case(0.1, (1, 10))]
+    #[test_case(1.1, (11, 10))]
+    #[test_case(1.91, (191, 100))]
+    #[test_case(1.0000000000000002, (5000000000000001, 5000000000000000))]
+    #[test_case(1e20, (100000000000000000000, 1))]
+    #[test_case(0.0, (0, 1))]
+    fn from_f64(value: f64, expected: (u128, u128)) {
+        let ratio = Ratio::from_f64(value).unwrap();
+        assert_eq!((ratio.numerator, ratio.denominator), expected);
+    }
+
+    #[test_case(f64::NAN)]
+    #[test_case(f64::INFINITY)]
+    #[test_case(-1.0)]
+    #[test_case(f64::MAX)]
+    #[test_case(1e39)]
+    fn from_f64_unrepresentable(value: f64) {
+        assert!(Ratio::from_f64(value).is_none());
+    }
+
+    #[test_case(1.91, Some((191, -2)))]
+    #[test_case(2.0, Some((2, 0)))]
+    #[test_case(1e300, Some((1, 300)))]
+    #[test_case(1.5e-10, Some((15, -11)))]
+    #[test_case(f64::MAX, Some((17976931348623157, 292)))]
+    #[test_case(f64::NAN, None)]
+    #[test_case(-1.0, None)]
+    fn decimal_digits(value: f64, expected: Option<(u128, i32)>) {
+        assert_eq!(super::decimal_digits(value), expected);
+    }
+
+    #[test_case((1, 2), (2, 3), Ordering::Less)]
+    #[test_case((3, 6), (1, 2), Ordering::Equal)]
+    #[test_case((u128::MAX, u128::MAX - 1), (u128::MAX - 1, u128::MAX - 2), Ordering::Less)]
+    #[test_case((u128::MAX, 1), (u128::MAX - 1, 1), Ordering::Greater)]
+    fn cmp(a: (u128, u128), b: (u128, u128), expected: Ordering) {
+        let a = Ratio::new(a.0, a.1);
+        let b = Ratio::new(b.0, b.1);
+        assert_eq!(a.cmp(&b), expected);
+    }
+}
@@ -41,7 +41,7 @@ fn approximate_rational(value: f64, max_denominator: i32) -> (i32, i32) {
     }
 
     let mut reciprocal_residual = value;
-    let mut continued_fraction_term = value.floor() as i32;
+    let mut continued_fraction_term = super::floor(value) as i32;
     let mut prev_convergent = (1, 0);
     let mut convergent = (continued_fraction_term, 1);
 
@@ -54,7 +54,7 @@ fn approximate_rational(value: f64, max_denominator: i32) -> (i32, i32) {
         }
 
         reciprocal_residual = 1.0 / next_residual;
-        continued_fraction_term = reciprocal_residual.floor() as i32;
+        continued_fraction_term = super::floor(reciprocal_residual) as i32;
 
         n = (max_denominator - prev_convergent.1) / convergent.1;
         if convergent.0 > 0 {
@@ -1,28 +1,64 @@
 //! Odds functionality and primitives.
 
-use std::{
+use core::{
     fmt::{Debug, Display},
+    hash::{Hash, Hasher},
     str::FromStr,
 };
 
+mod column;
+mod conversion;
 mod decimal;
+mod dynamic;
+mod error;
+#[cfg(feature = "exact")]
+mod exact;
+mod formatted;
 mod fractional;
+mod interpretation;
+mod locale;
 mod moneyline;
+mod parse;
+#[cfg(feature = "alloc")]
+mod registry;
 
+pub use column::detect_format;
+#[cfg(feature = "alloc")]
+pub use column::Column;
+pub use conversion::Conversion;
 pub use decimal::Decimal;
 use derive_more::Display;
+pub use dynamic::DynOdd;
+pub use error::{OddError, OddErrorKind, Rule};
+#[cfg(feature = "exact")]
+pub use exact::ExactOdd;
+pub use formatted::FormattedOdd;
 pub use fractional::Fractional;
+pub use interpretation::{FormatHint, Interpretations};
+pub use locale::{Locale, LocalizedDecimal, LocalizedMoneyline};
 pub use moneyline::Moneyline;
+#[cfg(feature = "alloc")]
+pub use registry::{CustomFormat, Registry};
 
-/// An error that can occur when creating an Odd.
-#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub enum OddError {
-    /// The odd is invalid. Usually this is because it does not make sense,
-    /// e.g. a fractional odd with a negative numerator or denominator.
-    Invalid,
+use crate::math::Ratio;
 
-    /// An error that occurred when parsing an odd.
-    ParseError,
+/// A format, or representation, of an odd.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Format {
+    /// A fractional odd, e.g. `5/2`.
+    #[display("fractional")]
+    Fractional,
+    /// A decimal odd, e.g. `3.5`.
+    #[display("decimal")]
+    Decimal,
+    /// A moneyline odd, e.g. `+250`.
+    #[display("moneyline")]
+    Moneyline,
+}
+
+impl Format {
+    /// Every format.
+    pub const ALL: [Format; 3] = [Format::Fractional, Format::Decimal, Format::Moneyline];
 }
 
 /// Any representation of an odd.
@@ -31,13 +67,24 @@ pub enum OddError {
 /// be different representations, such as:
 ///   - Parsing from a string that could be a fractional, decimal, moneyline, etc.
 ///   - Ordering or comparing odds of different types
+///
+/// With the `serde` feature, an odd is (de)serialized tagged with its format, e.g.
+/// `{"moneyline": -110}`, `{"decimal": 2.5}` or `{"fractional": "5/2"}`. Its
+/// [`Display`] is also self-describing, so it can instead be (de)serialized as a plain
+/// string with `wager::serde::string`, e.g. `"-110"`, `"2.5"`
+/// or `"5/2"`.
 #[derive(Debug, Clone, Copy, Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum AnyOdd {
     /// A fractional odd.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::string"))]
     Fractional(Fractional),
     /// A decimal odd.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::number"))]
     Decimal(Decimal),
     /// A moneyline odd.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::number"))]
     Moneyline(Moneyline),
 }
 
@@ -49,81 +96,152 @@ impl Odd for AnyOdd {
             AnyOdd::Moneyline(moneyline) => moneyline.payout(stake),
         }
     }
+
+    fn from_any(odd: AnyOdd) -> Result<Self, OddError> {
+        Ok(odd)
+    }
 }
 
 impl FromStr for AnyOdd {
     type Err = OddError;
 
+    /// Parse any odd, trying moneyline, decimal, then fractional.
+    ///
+    /// Only the built in formats are parsed, so custom formats are ignored even if
+    /// they have been added to a `Registry`, whose `parse` method tries them first.
+    ///
+    /// Use [`AnyOdd::parse_with`] to choose between formats differently, or
+    /// [`AnyOdd::interpret`] to find every plausible interpretation of the input.
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        input.parse::<Moneyline>().map(Into::into).or_else(|_| {
-            input
-                .parse::<Decimal>()
-                .map(Into::into)
-                .or_else(|_| input.parse::<Fractional>().map(Into::into))
-        })
+        AnyOdd::parse_with(input, FormatHint::default())
+    }
+}
+
+impl AnyOdd {
+    /// Parse any odd, choosing between the formats that the input is valid for
+    /// according to a hint.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::odd::{AnyOdd, Decimal, Format, FormatHint};
+    ///
+    /// let odd = AnyOdd::parse_with("150", FormatHint::Only(Format::Decimal)).unwrap();
+    /// assert_eq!(odd, AnyOdd::Decimal(Decimal::new(150.0).unwrap()));
+    ///
+    /// let odd = AnyOdd::parse_with("1/2", FormatHint::Only(Format::Decimal));
+    /// assert!(odd.is_err());
+    /// ```
+    pub fn parse_with(input: &str, hint: FormatHint) -> Result<Self, OddError> {
+        Interpretations::new(input).resolve(hint)
+    }
+
+    /// Find every plausible interpretation of an input, e.g. to detect whether
+    /// it is ambiguous.
+    pub fn interpret(input: &str) -> Interpretations {
+        Interpretations::new(input)
+    }
+
+    /// Whether the odd is even money, i.e. +100, 1/1 or 2.0.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::odd::{AnyOdd, Moneyline};
+    ///
+    /// assert!("-100".parse::<AnyOdd>().unwrap().is_evens());
+    /// assert!("evens".parse::<AnyOdd>().unwrap().is_evens());
+    /// assert_eq!(Moneyline::new(-100).unwrap(), Moneyline::EVENS);
+    /// ```
+    pub fn is_evens(&self) -> bool {
+        match self {
+            AnyOdd::Decimal(decimal) => decimal.is_evens(),
+            AnyOdd::Fractional(fractional) => fractional.is_evens(),
+            AnyOdd::Moneyline(moneyline) => moneyline.is_evens(),
+        }
+    }
+
+    /// Convert the odd to a decimal odd. See [`Odd::convert`].
+    pub fn to_decimal(&self) -> Result<Decimal, OddError> {
+        self.convert()
+    }
+
+    /// Convert the odd to a fractional odd. See [`Odd::convert`].
+    pub fn to_fractional(&self) -> Result<Fractional, OddError> {
+        self.convert()
+    }
+
+    /// Convert the odd to a moneyline odd. See [`Odd::convert`].
+    pub fn to_moneyline(&self) -> Result<Moneyline, OddError> {
+        self.convert()
+    }
+
+    /// Get the format of the odd.
+    pub fn format(&self) -> Format {
+        match self {
+            AnyOdd::Decimal(_) => Format::Decimal,
+            AnyOdd::Fractional(_) => Format::Fractional,
+            AnyOdd::Moneyline(_) => Format::Moneyline,
+        }
+    }
+
+    /// Display the odd with options, e.g. as another format or with a fixed number of
+    /// decimal places. See [`FormattedOdd`].
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::odd::{AnyOdd, Format};
+    ///
+    /// let odd: AnyOdd = "5/2".parse().unwrap();
+    /// assert_eq!(odd.formatted().format(Format::Moneyline).to_string(), "+250");
+    /// ```
+    pub fn formatted<'a>(&self) -> FormattedOdd<'a> {
+        FormattedOdd::new(*self)
+    }
+
+    /// Get the exact ratio of the payout to the stake, with decimal odds taken as the
+    /// decimal that they are displayed as, e.g. 11/10 for `1.1`.
+    ///
+    /// This is the canonical representation that equality, ordering and hashing are
+    /// based on, which keeps them consistent across the different types of odds. Only
+    /// decimal odds that are too large for a ratio lack one.
+    pub(crate) fn ratio(&self) -> Option<Ratio> {
+        match self {
+            AnyOdd::Decimal(decimal) => decimal.ratio(),
+            AnyOdd::Fractional(fractional) => Some(fractional.ratio()),
+            AnyOdd::Moneyline(moneyline) => Some(moneyline.ratio()),
+        }
     }
 }
 
 impl PartialEq for AnyOdd {
     fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (AnyOdd::Decimal(a), AnyOdd::Decimal(b)) => a == b,
-            (AnyOdd::Decimal(a), AnyOdd::Fractional(b)) => {
-                Decimal::try_from(*b).map(|b| *a == b).unwrap_or(false)
-            }
-            (AnyOdd::Decimal(a), AnyOdd::Moneyline(b)) => {
-                Decimal::try_from(*b).map(|b| *a == b).unwrap_or(false)
-            }
-            (AnyOdd::Fractional(a), AnyOdd::Fractional(b)) => a == b,
-            (AnyOdd::Fractional(a), AnyOdd::Decimal(b)) => {
-                Decimal::try_from(*a).map(|a| a == *b).unwrap_or(false)
-            }
-            (AnyOdd::Fractional(a), AnyOdd::Moneyline(b)) => {
-                Fractional::try_from(*b).map(|b| *a == b).unwrap_or(false)
-            }
-            (AnyOdd::Moneyline(a), AnyOdd::Moneyline(b)) => a == b,
-            (AnyOdd::Moneyline(a), AnyOdd::Decimal(b)) => {
-                Decimal::try_from(*a).map(|a| a == *b).unwrap_or(false)
-            }
-            (AnyOdd::Moneyline(a), AnyOdd::Fractional(b)) => {
-                Fractional::try_from(*a).map(|a| a == *b).unwrap_or(false)
-            }
-        }
+        self.cmp(other) == core::cmp::Ordering::Equal
     }
 }
 
 impl Eq for AnyOdd {}
 
+impl Hash for AnyOdd {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.ratio() {
+            Some(ratio) => ratio.hash(state),
+            None => self.payout(1.0).to_bits().hash(state),
+        }
+    }
+}
+
 impl PartialOrd for AnyOdd {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
 impl Ord for AnyOdd {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match (self, other) {
-            (AnyOdd::Decimal(a), AnyOdd::Decimal(b)) => a.cmp(b),
-            (AnyOdd::Decimal(a), AnyOdd::Fractional(b)) => Decimal::try_from(*b)
-                .map(|b| a.cmp(&b))
-                .unwrap_or(std::cmp::Ordering::Greater),
-            (AnyOdd::Decimal(a), AnyOdd::Moneyline(b)) => Decimal::try_from(*b)
-                .map(|b| a.cmp(&b))
-                .unwrap_or(std::cmp::Ordering::Greater),
-            (AnyOdd::Fractional(a), AnyOdd::Fractional(b)) => a.cmp(b),
-            (AnyOdd::Fractional(a), AnyOdd::Decimal(b)) => Decimal::try_from(*a)
-                .map(|a| a.cmp(b))
-                .unwrap_or(std::cmp::Ordering::Less),
-            (AnyOdd::Fractional(a), AnyOdd::Moneyline(b)) => Fractional::try_from(*b)
-                .map(|b| a.cmp(&b))
-                .unwrap_or(std::cmp::Ordering::Greater),
-            (AnyOdd::Moneyline(a), AnyOdd::Moneyline(b)) => a.cmp(b),
-            (AnyOdd::Moneyline(a), AnyOdd::Decimal(b)) => Decimal::try_from(*a)
-                .map(|a| a.cmp(b))
-                .unwrap_or(std::cmp::Ordering::Less),
-            (AnyOdd::Moneyline(a), AnyOdd::Fractional(b)) => Fractional::try_from(*a)
-                .map(|a| a.cmp(b))
-                .unwrap_or(std::cmp::Ordering::Less),
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        match (self.ratio(), other.ratio()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            // Odds without a ratio are larger than any odd that has one, and are exactly
+            // represented by their floats.
+            _ => self.payout(1.0).total_cmp(&other.payout(1.0)),
         }
     }
 }
@@ -139,6 +257,7 @@ pub trait Odd:
     + Eq
     + PartialOrd
     + Ord
+    + Hash
     + Into<AnyOdd>
     + FromStr<Err = OddError>
     + 'static
@@ -153,14 +272,70 @@ pub trait Odd:
     /// assert_eq!(fractional.payout(100.0), 500.0);
     /// ```
     fn payout(&self, stake: f64) -> f64;
+
+    /// Convert any odd to this type.
+    ///
+    /// This will error if the odd cannot be represented by this type. Conversions are
+    /// not always exact, see [`Conversion`] to find out whether they are.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::odd::{AnyOdd, Fractional, Odd};
+    ///
+    /// let odd: AnyOdd = "2.5".parse().unwrap();
+    /// assert_eq!(Fractional::from_any(odd), Fractional::new(3, 2));
+    /// ```
+    fn from_any(odd: AnyOdd) -> Result<Self, OddError>;
+
+    /// Convert the odd to an [`AnyOdd`].
+    fn to_any(&self) -> AnyOdd {
+        (*self).into()
+    }
+
+    /// Convert the odd to another type.
+    ///
+    /// This will error if the odd cannot be represented by the other type. Conversions
+    /// are not always exact, see [`Conversion`] to find out whether they are.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::odd::{Decimal, Moneyline, Odd};
+    ///
+    /// fn to_decimals<T: Odd>(odds: &[T]) -> Vec<Decimal> {
+    ///     odds.iter().filter_map(|odd| odd.convert().ok()).collect()
+    /// }
+    ///
+    /// let moneylines = [Moneyline::new(150).unwrap(), Moneyline::new(-200).unwrap()];
+    /// assert_eq!(
+    ///     to_decimals(&moneylines),
+    ///     [Decimal::new(2.5).unwrap(), Decimal::new(1.5).unwrap()]
+    /// );
+    /// ```
+    fn convert<T: Odd>(&self) -> Result<T, OddError> {
+        T::from_any(self.to_any())
+    }
 }
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use core::cmp::Ordering;
+    use proptest::prelude::*;
     use test_case::test_case;
 
+    #[test_case("+100"; "positive_moneyline")]
+    #[test_case("-100"; "negative_moneyline")]
+    #[test_case("1/1")]
+    #[test_case("2/2")]
+    #[test_case("2.0")]
+    #[test_case("evens")]
+    fn is_evens(input: &str) {
+        let odd: AnyOdd = input.parse().unwrap();
+        assert!(odd.is_evens());
+        assert_eq!(odd, AnyOdd::Decimal(Decimal::EVENS));
+    }
+
     #[test_case(Fractional::new(1, 2).unwrap(), Decimal::new(1.5).unwrap())]
     #[test_case(Fractional::new(2, 1).unwrap(), Decimal::new(3.0).unwrap())]
     #[test_case(Fractional::new(7, 9).unwrap(), Decimal::new(1.7777777777777777).unwrap())]
@@ -209,12 +384,66 @@ mod tests {
         assert_eq!(TryInto::<Decimal>::try_into(value).unwrap(), expected);
     }
 
+    #[test_case("1/2", "1.5", "1/2", "-200")]
+    #[test_case("2.5", "2.5", "3/2", "+150")]
+    #[test_case("-129", "1.7751937984496124", "100/129", "-129")]
+    #[test_case("7/9", "1.7777777777777777", "7/9", "-129")]
+    fn any_to(input: &str, decimal: &str, fractional: &str, moneyline: &str) {
+        let odd: AnyOdd = input.parse().unwrap();
+        assert_eq!(odd.to_decimal().unwrap().to_string(), decimal);
+        assert_eq!(odd.to_fractional().unwrap().to_string(), fractional);
+        assert_eq!(odd.to_moneyline().unwrap().to_string(), moneyline);
+    }
+
+    #[test]
+    fn any_to_invalid() {
+        let odd = AnyOdd::Decimal(Decimal::new(1.0).unwrap());
+        assert!(odd.to_fractional().is_err());
+        assert!(odd.to_moneyline().is_err());
+        assert_eq!(odd.to_decimal().unwrap(), Decimal::new(1.0).unwrap());
+    }
+
+    fn convert_all<S: Odd, T: Odd>(odds: &[S]) -> Vec<T> {
+        odds.iter().map(|odd| odd.convert().unwrap()).collect()
+    }
+
+    #[test]
+    fn convert_generic() {
+        let fractionals = [
+            Fractional::new(1, 2).unwrap(),
+            Fractional::new(2, 1).unwrap(),
+        ];
+
+        let moneylines: Vec<Moneyline> = convert_all(&fractionals);
+        assert_eq!(
+            moneylines,
+            [Moneyline::new(-200).unwrap(), Moneyline::new(200).unwrap()]
+        );
+
+        let decimals: Vec<Decimal> = convert_all(&moneylines);
+        assert_eq!(
+            decimals,
+            [Decimal::new(1.5).unwrap(), Decimal::new(3.0).unwrap()]
+        );
+
+        let anys: Vec<AnyOdd> = convert_all(&decimals);
+        assert_eq!(anys, [fractionals[0].to_any(), fractionals[1].to_any()]);
+        assert_eq!(Fractional::from_any(anys[1]).unwrap(), fractionals[1]);
+    }
+
     #[test_case("1/2", AnyOdd::Fractional(Fractional::new(1, 2).unwrap()))]
     #[test_case("2852 /  124", AnyOdd::Fractional(Fractional::new(23, 1).unwrap()))]
     #[test_case("1.5", AnyOdd::Decimal(Decimal::new(1.5).unwrap()))]
     #[test_case("1.7777777777777777", AnyOdd::Decimal(Decimal::new(1.7777777777777777).unwrap()))]
     #[test_case("-200", AnyOdd::Moneyline(Moneyline::new(-200).unwrap()))]
     #[test_case("+1200", AnyOdd::Moneyline(Moneyline::new(1200).unwrap()))]
+    #[test_case(" -110 ", AnyOdd::Moneyline(Moneyline::new(-110).unwrap()))]
+    #[test_case("EVS", AnyOdd::Moneyline(Moneyline::new(100).unwrap()))]
+    #[test_case("7-2", AnyOdd::Fractional(Fractional::new(7, 2).unwrap()))]
+    #[test_case("7 to 2", AnyOdd::Fractional(Fractional::new(7, 2).unwrap()))]
+    #[test_case("2/7 on", AnyOdd::Fractional(Fractional::new(2, 7).unwrap()))]
+    #[test_case("7/2 on", AnyOdd::Fractional(Fractional::new(2, 7).unwrap()))]
+    #[test_case("2½", AnyOdd::Fractional(Fractional::new(5, 2).unwrap()); "vulgar fraction")]
     fn parse(input: &str, expected: AnyOdd) {
         assert_eq!(input.parse::<AnyOdd>().unwrap(), expected);
     }
@@ -229,6 +458,42 @@ mod tests {
         assert_eq!(format!("{}", value), expected);
     }
 
+    #[test_case("1/2", "-200", true)]
+    #[test_case("-200", "1.5", true)]
+    #[test_case("1/1", "+100", true)]
+    #[test_case("+100", "-100", true)]
+    #[test_case("7/9", "1.7777777777777777", false)]
+    #[test_case("-129", "1.7751937984496124", false)]
+    #[test_case("1.1", "1/10", true)]
+    #[test_case("1.91", "91/100", true)]
+    #[test_case("1.91", "-110", false)]
+    fn eq(a: &str, b: &str, expected: bool) {
+        let a = a.parse::<AnyOdd>().unwrap();
+        let b = b.parse::<AnyOdd>().unwrap();
+        assert_eq!(a == b, expected);
+        assert_eq!(b == a, expected);
+        assert_eq!(a.cmp(&b) == core::cmp::Ordering::Equal, expected);
+    }
+
+    #[test]
+    fn hash() {
+        let odds: std::collections::HashSet<AnyOdd> = ["1/2", "-200", "1.5", "2/1", "+200", "3.0"]
+            .iter()
+            .map(|v| v.parse::<AnyOdd>().unwrap())
+            .collect();
+
+        assert_eq!(odds.len(), 2);
+        assert!(odds.contains(&"2/4".parse::<AnyOdd>().unwrap()));
+        assert!(odds.contains(&"+200".parse::<AnyOdd>().unwrap()));
+        assert!(!odds.contains(&"1.1".parse::<AnyOdd>().unwrap()));
+
+        let odds: std::collections::HashSet<AnyOdd> = ["1.1", "1/10", "1.91", "91/100"]
+            .iter()
+            .map(|v| v.parse::<AnyOdd>().unwrap())
+            .collect();
+        assert_eq!(odds.len(), 2);
+    }
+
     #[test_case(&["2/1", "1/2"], &["1/2", "2/1"])]
     #[test_case(&["1/2", "2/1"], &["1/2", "2/1"])]
     #[test_case(&["-200", "+100"], &["-200", "+100"])]
@@ -248,4 +513,85 @@ mod tests {
             assert_eq!(value.to_string().as_str(), expected[i], "index: {}", i);
         }
     }
+
+    fn fractional() -> impl Strategy<Value = Fractional> {
+        (1u64..1_000_000, 1u64..1_000_000)
+            .prop_map(|(numerator, denominator)| Fractional::new(numerator, denominator).unwrap())
+    }
+
+    fn decimal() -> impl Strategy<Value = Decimal> {
+        (1.0f64..10_000.0).prop_map(|value| Decimal::new(value).unwrap())
+    }
+
+    fn moneyline() -> impl Strategy<Value = Moneyline> {
+        (-100_000_000i64..100_000_000, 0u32..3)
+            .prop_filter_map("magnitude below 100", |(mantissa, places)| {
+                Moneyline::from_parts(mantissa, places).ok()
+            })
+    }
+
+    fn any_odd() -> impl Strategy<Value = AnyOdd> {
+        prop_oneof![
+            fractional().prop_map(AnyOdd::from),
+            decimal().prop_map(AnyOdd::from),
+            moneyline().prop_map(AnyOdd::from),
+        ]
+    }
+
+    /// Assert that the ordering of two odds agrees with their payouts, allowing for the
+    /// rounding of payouts that are equal.
+    fn assert_ordered_by_payout<T: Odd>(a: T, b: T) -> Result<(), TestCaseError> {
+        let (payout_a, payout_b) = (a.payout(1.0), b.payout(1.0));
+
+        match a.cmp(&b) {
+            Ordering::Less => prop_assert!(payout_a <= payout_b),
+            Ordering::Greater => prop_assert!(payout_a >= payout_b),
+            Ordering::Equal => prop_assert!((payout_a - payout_b).abs() <= 1e-12 * payout_a),
+        }
+
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn fractional_ordered_by_payout(a in fractional(), b in fractional()) {
+            assert_ordered_by_payout(a, b)?;
+        }
+
+        #[test]
+        fn decimal_ordered_by_payout(a in decimal(), b in decimal()) {
+            assert_ordered_by_payout(a, b)?;
+        }
+
+        #[test]
+        fn moneyline_ordered_by_payout(a in moneyline(), b in moneyline()) {
+            assert_ordered_by_payout(a, b)?;
+        }
+
+        #[test]
+        fn any_odd_ordered_by_payout(a in any_odd(), b in any_odd()) {
+            assert_ordered_by_payout(a, b)?;
+        }
+
+        #[test]
+        fn any_odd_ordered_consistently(a in any_odd(), b in any_odd(), c in any_odd()) {
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            prop_assert_eq!(a == b, a.cmp(&b).is_eq());
+
+            if a <= b && b <= c {
+                prop_assert!(a <= c);
+            }
+        }
+
+        #[test]
+        fn moneyline_ordered_as_any_odd(a in moneyline(), b in moneyline()) {
+            prop_assert_eq!(a.cmp(&b), AnyOdd::from(a).cmp(&AnyOdd::from(b)));
+        }
+
+        #[test]
+        fn moneyline_converted_exactly(a in moneyline()) {
+            let fractional = Fractional::try_from(a).unwrap();
+            prop_assert_eq!(AnyOdd::from(a), AnyOdd::from(fractional));
+        }
+    }
 }
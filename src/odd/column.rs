@@ -0,0 +1,237 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use super::OddError;
+use super::{parse, AnyOdd, Format, FormatHint};
+
+/// A column of odds that have all been parsed as the same format.
+///
+/// Parsing each value of a column individually can choose different formats for
+/// different rows, e.g. `"150"` as a moneyline but `"1.5"` as a decimal. A column
+/// instead detects the most likely format from all of its values and parses every
+/// value as that format, reporting the rows that disagree.
+///
+/// Example
+/// ```rust
+/// use wager::odd::{AnyOdd, Column, Format};
+///
+/// let column = Column::parse(&["1.5", "2.25", "150"]).unwrap();
+/// assert_eq!(column.format(), Format::Decimal);
+/// assert!(matches!(column.rows()[2], Ok(AnyOdd::Decimal(_))));
+///
+/// let column = Column::parse(&["+150", "-110", "2.5"]).unwrap();
+/// assert_eq!(column.format(), Format::Moneyline);
+/// assert_eq!(column.disagreements().map(|(row, _)| row).collect::<Vec<_>>(), [2]);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    format: Format,
+    rows: Vec<Result<AnyOdd, OddError>>,
+}
+
+#[cfg(feature = "alloc")]
+impl Column {
+    /// Parse a column of values as the format detected by [`detect_format`].
+    ///
+    /// Blank values don't count towards detecting the format, but are still reported
+    /// as disagreements so that rows line up with the values.
+    ///
+    /// Returns `None` if none of the values are valid odds in any format.
+    pub fn parse<S: AsRef<str>>(values: &[S]) -> Option<Self> {
+        detect_format(values).map(|format| Self::parse_as(values, format))
+    }
+
+    /// Parse a column of values as a known format. Blank values are reported as
+    /// disagreements.
+    pub fn parse_as<S: AsRef<str>>(values: &[S], format: Format) -> Self {
+        let rows = values
+            .iter()
+            .map(|value| AnyOdd::parse_with(value.as_ref(), FormatHint::Only(format)))
+            .collect();
+
+        Self { format, rows }
+    }
+
+    /// Get the format that every row was parsed as.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Get the result of parsing each row, in the same order as the values.
+    pub fn rows(&self) -> &[Result<AnyOdd, OddError>] {
+        &self.rows
+    }
+
+    /// Get the index and odd of each row that is valid in the column's format.
+    pub fn odds(&self) -> impl Iterator<Item = (usize, AnyOdd)> + '_ {
+        self.rows
+            .iter()
+            .enumerate()
+            .filter_map(|(index, row)| row.as_ref().ok().map(|odd| (index, *odd)))
+    }
+
+    /// Get the index and error of each row that is not valid in the column's format.
+    pub fn disagreements(&self) -> impl Iterator<Item = (usize, &OddError)> + '_ {
+        self.rows
+            .iter()
+            .enumerate()
+            .filter_map(|(index, row)| row.as_ref().err().map(|error| (index, error)))
+    }
+}
+
+/// Detect the most likely format of a column of values, using all of the values jointly.
+///
+/// Each format is scored by the number of values that are plausibly odds of that format,
+/// then by the number of values that have features characteristic of it:
+///   - Fractional odds contain a `/`.
+///   - Moneyline odds have an explicit sign, or are integers of at least 100 in a column
+///     without any values below 100, which can't be moneyline odds.
+///   - Decimal odds contain a `.`, or are below 100.
+///
+/// Decimal odds are never written with an explicit sign, so signed values are not
+/// considered plausible decimals. Any remaining tie is broken by
+/// [`FormatHint::DEFAULT_ORDER`]. Blank values are ignored, and signs and separators
+/// are recognized in the same variations as when parsing, e.g. `−110` is signed.
+///
+/// Returns `None` if none of the values are valid odds in any format.
+pub fn detect_format<S: AsRef<str>>(values: &[S]) -> Option<Format> {
+    let mut scores = [(0usize, 0usize); 3];
+    // Unsigned moneyline odds are only characteristic if no value is below 100.
+    let (mut unsigned_moneylines, mut below_100) = (0, false);
+
+    for value in values {
+        let value = value.as_ref().trim();
+        if value.is_empty() {
+            continue;
+        }
+
+        let interpretations = AnyOdd::interpret(value);
+        let contains = |wanted| value.chars().any(|c| parse::normalize(c) == wanted);
+        let signed = value
+            .chars()
+            .next()
+            .is_some_and(|c| matches!(parse::normalize(c), '+' | '-'));
+
+        for (score, format) in scores.iter_mut().zip(FormatHint::DEFAULT_ORDER) {
+            let Some(odd) = interpretations.get(format) else {
+                continue;
+            };
+
+            let characteristic = match (format, odd) {
+                (Format::Fractional, _) => contains('/'),
+                (Format::Moneyline, _) => {
+                    unsigned_moneylines += usize::from(!signed);
+                    signed
+                }
+                (Format::Decimal, AnyOdd::Decimal(decimal)) => {
+                    if signed {
+                        continue;
+                    }
+
+                    below_100 |= decimal.value() < 100.0;
+                    contains('.') || decimal.value() < 100.0
+                }
+                _ => false,
+            };
+
+            score.0 += 1;
+            score.1 += usize::from(characteristic);
+        }
+    }
+
+    FormatHint::DEFAULT_ORDER
+        .into_iter()
+        .zip(scores)
+        .map(|(format, (plausible, characteristic))| match format {
+            Format::Moneyline if !below_100 => {
+                (format, (plausible, characteristic + unsigned_moneylines))
+            }
+            _ => (format, (plausible, characteristic)),
+        })
+        .filter(|(_, (plausible, _))| *plausible > 0)
+        .rev()
+        .max_by_key(|(_, score)| *score)
+        .map(|(format, _)| format)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(&["+150", "-110", "+200"], Some(Format::Moneyline))]
+    #[test_case(&["150", "200", "250"], Some(Format::Moneyline))]
+    #[test_case(&["1.5", "2.0", "150"], Some(Format::Decimal))]
+    #[test_case(&["2", "3", "4"], Some(Format::Decimal))]
+    #[test_case(&["5/2", "1/2", "3"], Some(Format::Fractional))]
+    #[test_case(&["+150", "+200", "3"], Some(Format::Moneyline))]
+    #[test_case(&["", "1.91", " "], Some(Format::Decimal))]
+    #[test_case(&["evens", "150", "200"], Some(Format::Moneyline))]
+    #[test_case(&["evens", "2.5"], Some(Format::Decimal))]
+    #[test_case(&["evens", "150", "3"], Some(Format::Decimal))]
+    #[test_case(&["\u{2212}110", "\u{FF0B}150", "3"], Some(Format::Moneyline); "normalized_signs")]
+    #[test_case(&["abc", ""], None)]
+    #[test_case(&[], None)]
+    fn detect(values: &[&str], expected: Option<Format>) {
+        assert_eq!(detect_format(values), expected);
+    }
+
+    #[test]
+    fn parse_consistently() {
+        let column = Column::parse(&["1.5", "150", "2"]).unwrap();
+
+        assert!(column
+            .odds()
+            .all(|(_, odd)| matches!(odd, AnyOdd::Decimal(_))));
+        assert_eq!(column.odds().count(), 3);
+        assert_eq!(column.disagreements().count(), 0);
+    }
+
+    #[test]
+    fn unsigned_moneylines() {
+        // "evens" and "150" could be moneyline odds, but "3" can't be.
+        let column = Column::parse(&["evens", "150", "3"]).unwrap();
+
+        assert_eq!(column.format(), Format::Decimal);
+        let rows: Vec<usize> = column.disagreements().map(|(row, _)| row).collect();
+        assert_eq!(rows, [0]);
+    }
+
+    #[test]
+    fn disagreements() {
+        let values = vec![
+            String::from("5/2"),
+            String::from("1.5"),
+            String::from("1/3"),
+            String::new(),
+        ];
+        let column = Column::parse(&values).unwrap();
+
+        assert_eq!(column.format(), Format::Fractional);
+        let rows: Vec<usize> = column.disagreements().map(|(row, _)| row).collect();
+        assert_eq!(rows, [1, 3]);
+        assert!(column
+            .disagreements()
+            .all(|(_, error)| error.format() == Format::Fractional));
+    }
+
+    #[test]
+    fn blanks() {
+        let values = ["1.5", "", " ", "2.25"];
+        assert_eq!(detect_format(&values), Some(Format::Decimal));
+
+        let column = Column::parse(&values).unwrap();
+        assert_eq!(column.rows().len(), values.len());
+        let rows: Vec<usize> = column.disagreements().map(|(row, _)| row).collect();
+        assert_eq!(rows, [1, 2]);
+    }
+
+    #[test]
+    fn parse_as() {
+        let column = Column::parse_as(&["150", "200"], Format::Decimal);
+        assert_eq!(column.rows()[0], "150.0".parse::<AnyOdd>());
+    }
+}
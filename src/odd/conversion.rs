@@ -0,0 +1,176 @@
+use super::{AnyOdd, Odd, OddError};
+
+/// The result of converting an odd to another type, along with how much the conversion
+/// changed its price.
+///
+/// Many conversions are approximate, e.g. moneyline odds are rounded to whole numbers
+/// and decimal odds are approximated by fractions with small denominators. A conversion
+/// reports whether it was exact and, if not, the error it introduced in the payout and
+/// the implied probability. Errors are signed, i.e. the converted value minus the
+/// original value.
+///
+/// Example
+/// ```rust
+/// use wager::odd::{Conversion, Decimal, Fractional, Moneyline};
+///
+/// let decimal = Decimal::new(2.5).unwrap();
+/// let conversion = Conversion::<Fractional>::convert(decimal).unwrap();
+/// assert_eq!(conversion.odd(), Fractional::new(3, 2).unwrap());
+/// assert!(conversion.is_exact());
+///
+/// let decimal = Decimal::new(1.91).unwrap();
+/// let conversion = Conversion::<Moneyline>::convert(decimal).unwrap();
+/// assert_eq!(conversion.odd(), Moneyline::new(-110).unwrap());
+/// assert!(!conversion.is_exact());
+/// assert!(conversion.payout_error() < 0.0);
+/// assert!(conversion.relative_probability_error() < 0.001);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Conversion<T> {
+    source: AnyOdd,
+    odd: T,
+}
+
+impl<T: Odd> Conversion<T> {
+    /// Convert an odd to another type.
+    ///
+    /// This will error if the odd cannot be represented by the other type.
+    pub fn convert(source: impl Odd) -> Result<Self, OddError> {
+        Ok(Self::new(source, source.convert()?))
+    }
+
+    /// Compare an odd to one that it was converted to by other means, e.g. a price that
+    /// was displayed to one that was traded.
+    pub fn new(source: impl Into<AnyOdd>, odd: T) -> Self {
+        Self {
+            source: source.into(),
+            odd,
+        }
+    }
+
+    /// Get the odd that was converted.
+    pub fn source(&self) -> AnyOdd {
+        self.source
+    }
+
+    /// Get the converted odd.
+    pub fn odd(&self) -> T {
+        self.odd
+    }
+
+    /// Whether the converted odd has exactly the same price as the original.
+    ///
+    /// Decimal odds are compared as written, e.g. `1.1` is exactly `1/10`.
+    pub fn is_exact(&self) -> bool {
+        self.odd.into() == self.source
+    }
+
+    /// Get the error in the total payout for a stake of one unit.
+    pub fn payout_error(&self) -> f64 {
+        if self.is_exact() {
+            return 0.0;
+        }
+
+        self.odd.payout(1.0) - self.source.payout(1.0)
+    }
+
+    /// Get the error in the total payout, relative to the original payout.
+    pub fn relative_payout_error(&self) -> f64 {
+        self.payout_error() / self.source.payout(1.0)
+    }
+
+    /// Get the error in the implied probability.
+    pub fn probability_error(&self) -> f64 {
+        if self.is_exact() {
+            return 0.0;
+        }
+
+        self.odd.payout(1.0).recip() - self.source.payout(1.0).recip()
+    }
+
+    /// Get the error in the implied probability, relative to the original probability.
+    pub fn relative_probability_error(&self) -> f64 {
+        self.probability_error() * self.source.payout(1.0)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::odd::{Decimal, Fractional, Moneyline};
+    use test_case::test_case;
+
+    #[test_case(2.5, (3, 2), true)]
+    #[test_case(1.75, (3, 4), true)]
+    #[test_case(1.1, (1, 10), true)]
+    #[test_case(1.91, (91, 100), true)]
+    #[test_case(1.7777777777777777, (7, 9), false)]
+    #[test_case(1.011, (1, 91), false)]
+    fn decimal_to_fractional(value: f64, expected: (u64, u64), exact: bool) {
+        let decimal = Decimal::new(value).unwrap();
+        let conversion = Conversion::<Fractional>::convert(decimal).unwrap();
+
+        assert_eq!(
+            conversion.odd(),
+            Fractional::new(expected.0, expected.1).unwrap()
+        );
+        assert_eq!(conversion.is_exact(), exact);
+        if exact {
+            assert_eq!(conversion.payout_error(), 0.0);
+            assert_eq!(conversion.probability_error(), 0.0);
+        }
+    }
+
+    #[test_case((5, 2), 250, true)]
+    #[test_case((1, 3), -300, true)]
+    #[test_case((2, 3), -150, true)]
+    #[test_case((3, 7), -233, false)]
+    #[test_case((1, 1000), -100000, true)]
+    fn fractional_to_moneyline(value: (u64, u64), expected: i64, exact: bool) {
+        let fractional = Fractional::new(value.0, value.1).unwrap();
+        let conversion = Conversion::<Moneyline>::convert(fractional).unwrap();
+
+        assert_eq!(conversion.odd().mantissa(), expected);
+        assert_eq!(conversion.is_exact(), exact);
+    }
+
+    #[test]
+    fn errors() {
+        // 1.91 is a profit of 0.91, which is a moneyline of -109.89...
+        let decimal = Decimal::new(1.91).unwrap();
+        let conversion = Conversion::<Moneyline>::convert(decimal).unwrap();
+
+        let payout = 1.0 + 100.0 / 110.0;
+        assert_eq!(conversion.payout_error(), payout - 1.91);
+        assert_eq!(conversion.relative_payout_error(), (payout - 1.91) / 1.91);
+        assert_eq!(conversion.probability_error(), 1.0 / payout - 1.0 / 1.91);
+        assert!((conversion.relative_probability_error() - (1.91 / payout - 1.0)).abs() < 1e-15);
+    }
+
+    #[test]
+    fn same_type() {
+        let moneyline = Moneyline::new(-110).unwrap();
+        let conversion = Conversion::<Moneyline>::convert(moneyline).unwrap();
+
+        assert!(conversion.is_exact());
+        assert_eq!(conversion.probability_error(), 0.0);
+    }
+
+    #[test]
+    fn new() {
+        let displayed = Moneyline::new(-110).unwrap();
+        let traded = Decimal::new(1.9).unwrap();
+        let conversion = Conversion::new(displayed, traded);
+
+        assert!(!conversion.is_exact());
+        assert!(conversion.payout_error() < 0.0);
+        assert!(conversion.probability_error() > 0.0);
+    }
+
+    #[test]
+    fn invalid() {
+        let decimal = Decimal::new(1.0).unwrap();
+        assert!(Conversion::<Moneyline>::convert(decimal).is_err());
+    }
+}
@@ -1,8 +1,14 @@
-use std::str::FromStr;
+use core::{
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 
-use derive_more::Display;
+use crate::math::{self, Ratio};
 
-use super::{AnyOdd, Fractional, Moneyline, Odd, OddError};
+use super::{
+    parse, AnyOdd, Format, Fractional, Locale, LocalizedDecimal, Moneyline, Odd, OddError, Rule,
+};
 
 /// A decimal odd.
 ///
@@ -10,18 +16,22 @@ use super::{AnyOdd, Fractional, Moneyline, Odd, OddError};
 /// original stake, to the stake itself.
 ///
 /// E.g. 1.5 means that for every unit staked, the bettor will profit 1.5 units.
-#[derive(Debug, Clone, Copy, Display, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[display("{value}")]
+#[cfg_attr(feature = "serde", serde(try_from = "DecimalFields"))]
 pub struct Decimal {
     value: f64,
 }
 
 impl Decimal {
+    /// Even money, i.e. 2.0.
+    pub const EVENS: Self = Self { value: 2.0 };
+
     /// Create a new decimal odd from a float.
     ///
     /// This will error if the value is less than 1.0 because anything less would imply
-    /// that the payout is less than the stake itself.
+    /// that the payout is less than the stake itself. It will also error if the value is
+    /// not finite.
     ///
     /// Example
     /// ```rust
@@ -33,18 +43,78 @@ impl Decimal {
     /// let decimal = Decimal::new(0.0);
     /// assert!(decimal.is_err());
     /// ```
-    pub fn new(value: f64) -> Result<Self, OddError> {
+    pub const fn new(value: f64) -> Result<Self, OddError> {
+        if !value.is_finite() {
+            return Err(OddError::invalid(Format::Decimal, Rule::DecimalNotFinite));
+        }
+
         if value < 1.0 {
-            return Err(OddError::Invalid);
+            return Err(OddError::invalid(Format::Decimal, Rule::DecimalBelowOne));
         }
 
         Ok(Self { value })
     }
 
+    /// Whether the decimal odd is even money.
+    pub fn is_evens(&self) -> bool {
+        *self == Self::EVENS
+    }
+
     /// Get the value of the decimal odd.
     pub fn value(&self) -> f64 {
         self.value
     }
+
+    /// Parse a decimal odd written with the separators of a locale, e.g. `"1,91"` in
+    /// [`Locale::DE`]. Thousands separators are optional, but must group exactly three
+    /// digits when present.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::odd::{Decimal, Locale};
+    ///
+    /// let decimal = Decimal::parse_localized("1.001,5", Locale::DE).unwrap();
+    /// assert_eq!(decimal.value(), 1001.5);
+    ///
+    /// let decimal = Decimal::parse_localized("1,91", Locale::EN);
+    /// assert!(decimal.is_err());
+    /// ```
+    pub fn parse_localized(input: &str, locale: Locale) -> Result<Self, OddError> {
+        let error = |kind| OddError::from_kind(Format::Decimal, kind).with_input(input);
+
+        let rewritten = parse::delocalize(input, locale).map_err(error)?;
+        let value =
+            parse::decimal(rewritten.as_str()).map_err(|kind| error(rewritten.locate(kind)))?;
+
+        Self::new(value).map_err(|error| error.with_input(input))
+    }
+
+    /// Display the decimal odd with the separators of a locale.
+    pub fn localized(&self, locale: Locale) -> LocalizedDecimal {
+        LocalizedDecimal::new(self.value, locale)
+    }
+
+    /// Get the ratio of the payout to the stake, as the decimal that the value is
+    /// displayed as, or `None` if it is too large to be represented.
+    pub(crate) fn ratio(&self) -> Option<Ratio> {
+        Ratio::from_f64(self.value)
+    }
+}
+
+/// The fields of a decimal odd as they are deserialized, before they are validated.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct DecimalFields {
+    value: f64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DecimalFields> for Decimal {
+    type Error = OddError;
+
+    fn try_from(fields: DecimalFields) -> Result<Self, Self::Error> {
+        Self::new(fields.value)
+    }
 }
 
 impl From<Decimal> for AnyOdd {
@@ -58,28 +128,55 @@ impl Odd for Decimal {
     fn payout(&self, stake: f64) -> f64 {
         stake * self.value
     }
+
+    fn from_any(odd: AnyOdd) -> Result<Self, OddError> {
+        match odd {
+            AnyOdd::Fractional(odd) => Self::try_from(odd),
+            AnyOdd::Decimal(odd) => Ok(odd),
+            AnyOdd::Moneyline(odd) => Self::try_from(odd),
+        }
+    }
+}
+
+impl Display for Decimal {
+    /// Display the value of the decimal odd, always with a decimal point so that it can
+    /// not be mistaken for a moneyline odd, e.g. `150.0` rather than `150`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if math::fract(self.value) == 0.0 {
+            write!(f, "{:.1}", self.value)
+        } else {
+            write!(f, "{}", self.value)
+        }
+    }
 }
 
 impl FromStr for Decimal {
     type Err = OddError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let value = input.trim().parse().map_err(|_| OddError::ParseError)?;
+        let value = parse::decimal(input)
+            .map_err(|kind| OddError::from_kind(Format::Decimal, kind).with_input(input))?;
 
-        Self::new(value)
+        Self::new(value).map_err(|error| error.with_input(input))
     }
 }
 
 impl Eq for Decimal {}
 
+impl Hash for Decimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.to_bits().hash(state);
+    }
+}
+
 impl PartialOrd for Decimal {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
 impl Ord for Decimal {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.value.total_cmp(&other.value)
     }
 }
@@ -99,7 +196,7 @@ impl TryFrom<Moneyline> for Decimal {
     type Error = OddError;
 
     fn try_from(value: Moneyline) -> Result<Self, Self::Error> {
-        let value = value.value() as f64;
+        let value = value.value_f64();
 
         if value > 0.0 {
             Self::new((value / 100.0) + 1.0)
@@ -113,6 +210,7 @@ impl TryFrom<Moneyline> for Decimal {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::odd::OddErrorKind;
     use test_case::test_case;
 
     #[test_case(1.5, 1.5)]
@@ -123,14 +221,30 @@ mod tests {
         assert_eq!(decimal.value(), expected);
     }
 
+    #[test]
+    fn evens() {
+        assert_eq!(Decimal::new(2.0).unwrap(), Decimal::EVENS);
+        assert!(Decimal::EVENS.is_evens());
+        assert!(!Decimal::new(2.0000001).unwrap().is_evens());
+    }
+
     #[test_case(0.5)]
     #[test_case(0.0)]
     #[test_case(-1.0)]
+    #[test_case(f64::NAN)]
+    #[test_case(f64::INFINITY)]
     fn invalid(value: f64) {
         let decimal = Decimal::new(value);
         assert!(decimal.is_err());
     }
 
+    #[test]
+    fn large() {
+        let decimal = Decimal::new(f64::MAX).unwrap();
+        assert_eq!(decimal.value(), f64::MAX);
+        assert!(AnyOdd::from(decimal) > AnyOdd::from(Decimal::new(1e20).unwrap()));
+    }
+
     #[test_case(1.5, 100.0, 150.0)]
     #[test_case(3.0, 25.0, 75.0)]
     #[test_case(1.7777777777777777, 100.0, 177.77777777777777)]
@@ -138,4 +252,53 @@ mod tests {
         let decimal = Decimal::new(value).unwrap();
         assert_eq!(decimal.payout(stake), expected);
     }
+
+    #[test_case("1,91", Locale::DE, 1.91)]
+    #[test_case("1.001,5", Locale::DE, 1001.5)]
+    #[test_case("1001,5", Locale::DE, 1001.5)]
+    #[test_case("1,234.5", Locale::EN, 1234.5)]
+    #[test_case("1\u{202f}234,5", Locale::FR, 1234.5; "fr")]
+    #[test_case(" 2,5 ", Locale::DE, 2.5)]
+    fn parse_localized(input: &str, locale: Locale, expected: f64) {
+        let decimal = Decimal::parse_localized(input, locale).unwrap();
+        assert_eq!(decimal.value(), expected);
+    }
+
+    #[test]
+    fn parse_localized_long() {
+        let padded = format!("{:>70}", "1,91");
+        assert_eq!(
+            Decimal::parse_localized(&padded, Locale::DE)
+                .unwrap()
+                .value(),
+            1.91
+        );
+
+        let long = format!("1,{}", "0".repeat(70));
+        assert_eq!(
+            Decimal::parse_localized(&long, Locale::DE)
+                .unwrap_err()
+                .kind(),
+            OddErrorKind::Invalid(Rule::OutOfRange)
+        );
+    }
+
+    #[test_case("1,91", Locale::EN, 1)]
+    #[test_case("1.91", Locale::DE, 1)]
+    #[test_case("1,2,3", Locale::DE, 3)]
+    #[test_case("1.234,567", Locale::EN, 5)]
+    fn parse_localized_invalid(input: &str, locale: Locale, position: usize) {
+        let error = Decimal::parse_localized(input, locale).unwrap_err();
+        assert_eq!(error.position(), Some(position));
+        #[cfg(feature = "alloc")]
+        assert_eq!(error.input(), Some(input));
+    }
+
+    #[test_case(2.5, "2.5")]
+    #[test_case(150.0, "150.0")]
+    #[test_case(1.0, "1.0")]
+    #[test_case(1e20, "100000000000000000000.0")]
+    fn display(value: f64, expected: &str) {
+        assert_eq!(Decimal::new(value).unwrap().to_string(), expected);
+    }
 }
@@ -0,0 +1,123 @@
+use core::fmt::{Debug, Display};
+
+use super::{AnyOdd, Conversion, Decimal, Fractional, Odd, OddError};
+
+/// An object-safe interface to odds, for when the type of odd is only known at runtime,
+/// e.g. `Box<dyn DynOdd>`.
+///
+/// [`Odd`] can't be made into a trait object because it requires `Copy`, `Ord` and
+/// `FromStr`. Every [`Odd`] implements this trait, and so can custom odds formats that
+/// aren't built in, see [`Registry`](super::Registry).
+///
+/// Example
+/// ```rust
+/// use wager::odd::{Decimal, DynOdd, Fractional, Moneyline};
+///
+/// let odds: Vec<Box<dyn DynOdd>> = vec![
+///     Box::new(Fractional::new(5, 2).unwrap()),
+///     Box::new(Decimal::new(3.5).unwrap()),
+///     Box::new(Moneyline::new(-200).unwrap()),
+/// ];
+///
+/// let payouts: Vec<f64> = odds.iter().map(|odd| odd.total_payout(10.0)).collect();
+/// assert_eq!(payouts, [35.0, 35.0, 15.0]);
+/// assert_eq!(odds[0].to_string(), "5/2");
+/// ```
+pub trait DynOdd: Debug + Display {
+    /// Get the total (including the stake) payout for a given stake.
+    fn total_payout(&self, stake: f64) -> f64;
+
+    /// Get the probability implied by the odd, i.e. the inverse of the payout for a
+    /// stake of one unit.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::odd::{DynOdd, Moneyline};
+    ///
+    /// let moneyline = Moneyline::new(-300).unwrap();
+    /// assert_eq!(moneyline.implied_probability(), 0.75);
+    /// ```
+    fn implied_probability(&self) -> f64 {
+        self.total_payout(1.0).recip()
+    }
+
+    /// Convert the odd to a decimal odd.
+    fn to_decimal(&self) -> Result<Decimal, OddError>;
+
+    /// Get the odd as a fractional odd if it is exactly a ratio, e.g. `1/2` for a Malay
+    /// odd of `0.5`. By default, no odd is assumed to be a ratio.
+    fn to_fractional(&self) -> Option<Fractional> {
+        None
+    }
+
+    /// Convert the odd to an [`AnyOdd`], e.g. to compare it with other odds.
+    ///
+    /// Odds that aren't one of the built in formats are converted to fractional odds
+    /// if they are [exactly a ratio](DynOdd::to_fractional), and to decimal odds
+    /// otherwise, which may lose precision.
+    fn any_odd(&self) -> Result<AnyOdd, OddError> {
+        match self.to_fractional() {
+            Some(fractional) => Ok(AnyOdd::Fractional(fractional)),
+            None => self.to_decimal().map(AnyOdd::Decimal),
+        }
+    }
+}
+
+impl<T: Odd> DynOdd for T {
+    fn total_payout(&self, stake: f64) -> f64 {
+        self.payout(stake)
+    }
+
+    fn to_decimal(&self) -> Result<Decimal, OddError> {
+        self.convert()
+    }
+
+    fn to_fractional(&self) -> Option<Fractional> {
+        Conversion::convert(*self)
+            .ok()
+            .filter(Conversion::is_exact)
+            .map(|conversion| conversion.odd())
+    }
+
+    fn any_odd(&self) -> Result<AnyOdd, OddError> {
+        Ok(self.to_any())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::odd::{Fractional, Moneyline};
+    use test_case::test_case;
+
+    #[test_case(Box::new(Fractional::new(1, 3).unwrap()), 0.75)]
+    #[test_case(Box::new(Decimal::new(4.0).unwrap()), 0.25)]
+    #[test_case(Box::new(Moneyline::new(100).unwrap()), 0.5)]
+    #[test_case(Box::new("-300".parse::<AnyOdd>().unwrap()), 0.75)]
+    fn implied_probability(odd: Box<dyn DynOdd>, expected: f64) {
+        assert_eq!(odd.implied_probability(), expected);
+    }
+
+    #[test]
+    fn any_odd() {
+        let fractional = Fractional::new(7, 9).unwrap();
+        let odd: &dyn DynOdd = &fractional;
+
+        assert_eq!(odd.any_odd().unwrap(), AnyOdd::Fractional(fractional));
+        assert_eq!(
+            odd.to_decimal().unwrap(),
+            Decimal::new(1.7777777777777777).unwrap()
+        );
+    }
+
+    #[test_case(Box::new(Fractional::new(7, 9).unwrap()), Some((7, 9)))]
+    #[test_case(Box::new(Decimal::new(2.5).unwrap()), Some((3, 2)))]
+    #[test_case(Box::new(Moneyline::new(-110).unwrap()), Some((10, 11)))]
+    #[test_case(Box::new(Decimal::new(1.0).unwrap()), None)]
+    fn to_fractional(odd: Box<dyn DynOdd>, expected: Option<(u64, u64)>) {
+        let expected = expected
+            .map(|(numerator, denominator)| Fractional::new(numerator, denominator).unwrap());
+        assert_eq!(odd.to_fractional(), expected);
+    }
+}
@@ -0,0 +1,248 @@
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, string::String};
+use core::fmt;
+
+use derive_more::Display;
+
+use super::Format;
+
+/// An error that can occur when creating an Odd.
+///
+/// Carries the [format](Format) that was attempted, what went wrong, and the
+/// offending input when the error came from parsing a string. The input is only kept
+/// with the `alloc` feature.
+///
+/// Example
+/// ```rust
+/// use wager::odd::{Format, Moneyline, OddErrorKind, Rule};
+///
+/// let error = "+50".parse::<Moneyline>().unwrap_err();
+/// assert_eq!(error.format(), Format::Moneyline);
+/// assert_eq!(error.kind(), OddErrorKind::Invalid(Rule::MoneylineBelow100));
+///
+/// // The input is only kept with the `alloc` feature.
+/// if let Some(input) = error.input() {
+///     assert_eq!(input, "+50");
+///     assert_eq!(
+///         error.to_string(),
+///         "invalid moneyline odd \"+50\": moneyline magnitude below 100"
+///     );
+/// }
+///
+/// let error = "1/x".parse::<wager::odd::Fractional>().unwrap_err();
+/// assert_eq!(error.position(), Some(2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OddError {
+    kind: OddErrorKind,
+    format: Format,
+    #[cfg(feature = "alloc")]
+    input: Option<String>,
+}
+
+/// The kind of an [`OddError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OddErrorKind {
+    /// The input could not be parsed.
+    Parse {
+        /// The byte position in the input at which parsing failed.
+        position: usize,
+    },
+
+    /// The odd is invalid because it violates a [`Rule`], e.g. a fractional odd
+    /// with a zero numerator.
+    Invalid(Rule),
+}
+
+/// A validation rule that an odd must satisfy.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// The magnitude of a moneyline odd must be at least 100.
+    #[display("moneyline magnitude below 100")]
+    MoneylineBelow100,
+
+    /// A decimal odd must be at least 1.0.
+    #[display("decimal below 1.0")]
+    DecimalBelowOne,
+
+    /// A decimal odd must be a finite number.
+    #[display("decimal not finite")]
+    DecimalNotFinite,
+
+    /// The numerator of a fractional odd must not be zero.
+    #[display("numerator is zero")]
+    ZeroNumerator,
+
+    /// The denominator of a fractional odd must not be zero.
+    #[display("denominator is zero")]
+    ZeroDenominator,
+
+    /// The value must fit within the range supported by the format.
+    #[display("value out of range")]
+    OutOfRange,
+}
+
+impl OddError {
+    /// Create an error for an odd that violates a rule.
+    pub(crate) const fn invalid(format: Format, rule: Rule) -> Self {
+        Self::from_kind(format, OddErrorKind::Invalid(rule))
+    }
+
+    /// Create an error of the given kind.
+    pub(crate) const fn from_kind(format: Format, kind: OddErrorKind) -> Self {
+        Self {
+            kind,
+            format,
+            #[cfg(feature = "alloc")]
+            input: None,
+        }
+    }
+
+    /// Attach the offending input to the error.
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables, unused_mut))]
+    pub(crate) fn with_input(mut self, input: &str) -> Self {
+        #[cfg(feature = "alloc")]
+        {
+            self.input = Some(input.to_owned());
+        }
+        self
+    }
+
+    /// Get the kind of error.
+    pub const fn kind(&self) -> OddErrorKind {
+        self.kind
+    }
+
+    /// Get the format of odd that was being created.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Get the input that caused the error, if the error came from parsing.
+    pub fn input(&self) -> Option<&str> {
+        #[cfg(feature = "alloc")]
+        return self.input.as_deref();
+        #[cfg(not(feature = "alloc"))]
+        return None;
+    }
+
+    /// Get the byte position in the input at which parsing failed, if any.
+    pub fn position(&self) -> Option<usize> {
+        match self.kind {
+            OddErrorKind::Parse { position } => Some(position),
+            OddErrorKind::Invalid(_) => None,
+        }
+    }
+
+    /// Get the validation rule that was violated, if any.
+    pub fn rule(&self) -> Option<Rule> {
+        match self.kind {
+            OddErrorKind::Parse { .. } => None,
+            OddErrorKind::Invalid(rule) => Some(rule),
+        }
+    }
+}
+
+impl fmt::Display for OddError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.kind, self.input()) {
+            (OddErrorKind::Parse { position }, Some(input)) => write!(
+                f,
+                "failed to parse {} odd from {:?}: unexpected input at byte {}",
+                self.format, input, position
+            ),
+            (OddErrorKind::Parse { position }, None) => write!(
+                f,
+                "failed to parse {} odd: unexpected input at byte {}",
+                self.format, position
+            ),
+            (OddErrorKind::Invalid(rule), Some(input)) => {
+                write!(f, "invalid {} odd {:?}: {}", self.format, input, rule)
+            }
+            (OddErrorKind::Invalid(rule), None) => {
+                write!(f, "invalid {} odd: {}", self.format, rule)
+            }
+        }
+    }
+}
+
+impl core::error::Error for OddError {}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::odd::{AnyOdd, Decimal, Fractional, Moneyline};
+    use test_case::test_case;
+
+    #[test_case("abc", Format::Moneyline, OddErrorKind::Parse { position: 0 })]
+    #[test_case("0.5", Format::Decimal, OddErrorKind::Invalid(Rule::DecimalBelowOne))]
+    #[test_case(
+        "1/0",
+        Format::Fractional,
+        OddErrorKind::Invalid(Rule::ZeroDenominator)
+    )]
+    #[test_case("12/3x", Format::Fractional, OddErrorKind::Parse { position: 4 })]
+    #[test_case("1.5.", Format::Decimal, OddErrorKind::Parse { position: 3 })]
+    fn any_odd(input: &str, format: Format, kind: OddErrorKind) {
+        let error = input.parse::<AnyOdd>().unwrap_err();
+        assert_eq!(error.format(), format);
+        assert_eq!(error.kind(), kind);
+        #[cfg(feature = "alloc")]
+        assert_eq!(error.input(), Some(input));
+    }
+
+    #[test_case("+1x0", OddErrorKind::Parse { position: 2 })]
+    #[test_case("", OddErrorKind::Parse { position: 0 })]
+    #[test_case("-99", OddErrorKind::Invalid(Rule::MoneylineBelow100))]
+    #[test_case("99999999999999999999", OddErrorKind::Invalid(Rule::OutOfRange))]
+    fn moneyline(input: &str, kind: OddErrorKind) {
+        let error = input.parse::<Moneyline>().unwrap_err();
+        assert_eq!(error.kind(), kind);
+    }
+
+    #[test_case(" 1.5x", OddErrorKind::Parse { position: 4 })]
+    #[test_case("1e", OddErrorKind::Parse { position: 1 })]
+    #[test_case("inf", OddErrorKind::Invalid(Rule::DecimalNotFinite))]
+    fn decimal(input: &str, kind: OddErrorKind) {
+        let error = input.parse::<Decimal>().unwrap_err();
+        assert_eq!(error.kind(), kind);
+    }
+
+    #[test_case("5", OddErrorKind::Parse { position: 1 })]
+    #[test_case("5 / -2", OddErrorKind::Parse { position: 4 })]
+    #[test_case("5/2/1", OddErrorKind::Parse { position: 3 })]
+    #[test_case("0/2", OddErrorKind::Invalid(Rule::ZeroNumerator))]
+    #[test_case("99999999999999999999/1", OddErrorKind::Invalid(Rule::OutOfRange))]
+    fn fractional(input: &str, kind: OddErrorKind) {
+        let error = input.parse::<Fractional>().unwrap_err();
+        assert_eq!(error.kind(), kind);
+    }
+
+    #[test]
+    fn display_without_input() {
+        let error = Decimal::new(0.5).unwrap_err();
+        assert_eq!(error.to_string(), "invalid decimal odd: decimal below 1.0");
+    }
+
+    #[test]
+    fn display_parse() {
+        let error = "1/x".parse::<Fractional>().unwrap_err();
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            error.to_string(),
+            "failed to parse fractional odd from \"1/x\": unexpected input at byte 2"
+        );
+        #[cfg(not(feature = "alloc"))]
+        assert_eq!(
+            error.to_string(),
+            "failed to parse fractional odd: unexpected input at byte 2"
+        );
+    }
+
+    #[test]
+    fn source() {
+        let error: Box<dyn core::error::Error> = Box::new(Decimal::new(0.5).unwrap_err());
+        assert!(error.source().is_none());
+    }
+}
@@ -0,0 +1,354 @@
+use core::{
+    fmt::{self, Display},
+    iter::Product,
+    ops::{Mul, MulAssign},
+    str::FromStr,
+};
+
+use num_bigint::BigUint;
+use num_rational::Ratio;
+use num_traits::ToPrimitive;
+
+use super::{AnyOdd, Decimal, DynOdd, Format, Fractional, Moneyline, Odd, OddError, Rule};
+use crate::math;
+
+/// An odd represented exactly, as the ratio of its total payout to its stake with
+/// integers of any size.
+///
+/// Every other type of odd converts to an exact odd without loss, and exact odds can be
+/// multiplied and compared without overflowing or rounding, e.g. to price a parlay of
+/// many long prices. Requires the `exact` feature.
+///
+/// The integers and ratios are those of the [`num_bigint`](crate::num_bigint) and
+/// [`num_rational`](crate::num_rational) crates, which are re-exported.
+///
+/// Example
+/// ```rust
+/// use wager::odd::{AnyOdd, ExactOdd, Fractional, Moneyline};
+///
+/// let legs: Vec<ExactOdd> = ["1000/1", "999/1", "1001/1", "5000/1", "7500/1", "9999/1"]
+///     .iter()
+///     .map(|leg| leg.parse().unwrap())
+///     .collect();
+///
+/// // The payout of the parlay overflows a `u64`, but is still exact.
+/// let parlay = ExactOdd::parlay(legs.iter().cloned());
+/// assert!(parlay.to_fractional().is_err());
+/// assert!(parlay > ExactOdd::parlay(legs[1..].iter().cloned()));
+///
+/// let moneyline = ExactOdd::from(Moneyline::new(-110).unwrap());
+/// assert_eq!(moneyline.to_string(), "10/11");
+/// assert_eq!(moneyline, ExactOdd::from(Fractional::new(10, 11).unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExactOdd {
+    payout: Ratio<BigUint>,
+}
+
+impl ExactOdd {
+    /// Create a new exact odd from the ratio of its total payout to its stake, e.g.
+    /// `5/2` for a decimal odd of 2.5.
+    ///
+    /// This will error if the denominator is zero or the ratio is less than one.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::odd::{Decimal, ExactOdd};
+    ///
+    /// let exact = ExactOdd::new(5u8.into(), 2u8.into()).unwrap();
+    /// assert_eq!(exact, ExactOdd::from(Decimal::new(2.5).unwrap()));
+    ///
+    /// assert!(ExactOdd::new(1u8.into(), 2u8.into()).is_err());
+    /// ```
+    pub fn new(numerator: BigUint, denominator: BigUint) -> Result<Self, OddError> {
+        if denominator == BigUint::ZERO {
+            return Err(OddError::invalid(Format::Fractional, Rule::ZeroDenominator));
+        }
+
+        if numerator < denominator {
+            return Err(OddError::invalid(Format::Decimal, Rule::DecimalBelowOne));
+        }
+
+        Ok(Self {
+            payout: Ratio::new(numerator, denominator),
+        })
+    }
+
+    /// Price a parlay, i.e. a bet on every leg winning, whose payout is the product of
+    /// the payouts of its legs.
+    ///
+    /// A parlay without any legs has a payout of one, i.e. it returns the stake with no
+    /// profit (`0/1`).
+    pub fn parlay<I>(legs: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<ExactOdd>,
+    {
+        legs.into_iter().map(Into::into).product()
+    }
+
+    /// Sum the implied probabilities of odds, e.g. of every outcome of a market.
+    ///
+    /// The sum is greater than one by the bookmaker's margin, or less than one if the
+    /// odds offer an arbitrage.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::num_rational::Ratio;
+    /// use wager::odd::{ExactOdd, Moneyline};
+    ///
+    /// let odds = [-110, -110].map(|value| ExactOdd::from(Moneyline::new(value).unwrap()));
+    /// assert_eq!(ExactOdd::book(&odds), Ratio::new(22u8.into(), 21u8.into()));
+    /// ```
+    pub fn book<'a, I>(odds: I) -> Ratio<BigUint>
+    where
+        I: IntoIterator<Item = &'a ExactOdd>,
+    {
+        odds.into_iter().map(ExactOdd::probability).sum()
+    }
+
+    /// Get the exact ratio of the total payout to the stake.
+    pub fn payout_ratio(&self) -> &Ratio<BigUint> {
+        &self.payout
+    }
+
+    /// Get the exact ratio of the profit to the stake, i.e. the fractional odd.
+    pub fn profit_ratio(&self) -> Ratio<BigUint> {
+        &self.payout - BigUint::from(1u8)
+    }
+
+    /// Get the exact probability implied by the odd.
+    pub fn probability(&self) -> Ratio<BigUint> {
+        self.payout.recip()
+    }
+
+    /// Convert the odd to a fractional odd, without loss.
+    ///
+    /// This will error if the profit is zero, or the numerator or denominator of the
+    /// profit is too large for a [`Fractional`].
+    pub fn to_fractional(&self) -> Result<Fractional, OddError> {
+        let profit = self.profit_ratio();
+        let out_of_range = || OddError::invalid(Format::Fractional, Rule::OutOfRange);
+
+        let numerator = u64::try_from(profit.numer()).map_err(|_| out_of_range())?;
+        let denominator = u64::try_from(profit.denom()).map_err(|_| out_of_range())?;
+
+        Fractional::new(numerator, denominator)
+    }
+
+    /// Get the payout for a stake of one unit as a float, which is infinite if it is
+    /// too large to represent.
+    fn payout_f64(&self) -> f64 {
+        self.payout.to_f64().unwrap_or(f64::INFINITY)
+    }
+}
+
+impl From<AnyOdd> for ExactOdd {
+    fn from(value: AnyOdd) -> Self {
+        let payout = match value.ratio() {
+            Some(ratio) => Ratio::new(ratio.numerator().into(), ratio.denominator().into()),
+            // Decimal odds that are too large for a ratio are whole numbers.
+            None => {
+                let (digits, exponent) =
+                    math::decimal_digits(value.payout(1.0)).unwrap_or_default();
+                let scale = BigUint::from(10u8).pow(exponent.unsigned_abs());
+                Ratio::from_integer(BigUint::from(digits) * scale)
+            }
+        };
+
+        Self { payout }
+    }
+}
+
+impl From<Fractional> for ExactOdd {
+    fn from(value: Fractional) -> Self {
+        AnyOdd::from(value).into()
+    }
+}
+
+impl From<Decimal> for ExactOdd {
+    fn from(value: Decimal) -> Self {
+        AnyOdd::from(value).into()
+    }
+}
+
+impl From<Moneyline> for ExactOdd {
+    fn from(value: Moneyline) -> Self {
+        AnyOdd::from(value).into()
+    }
+}
+
+impl TryFrom<ExactOdd> for Fractional {
+    type Error = OddError;
+
+    fn try_from(value: ExactOdd) -> Result<Self, Self::Error> {
+        value.to_fractional()
+    }
+}
+
+impl Mul for ExactOdd {
+    type Output = ExactOdd;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            payout: self.payout * rhs.payout,
+        }
+    }
+}
+
+impl MulAssign for ExactOdd {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.payout *= rhs.payout;
+    }
+}
+
+impl Product for ExactOdd {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self {
+            payout: iter.map(|odd| odd.payout).product(),
+        }
+    }
+}
+
+impl DynOdd for ExactOdd {
+    fn total_payout(&self, stake: f64) -> f64 {
+        stake * self.payout_f64()
+    }
+
+    fn to_decimal(&self) -> Result<Decimal, OddError> {
+        Decimal::new(self.payout_f64())
+    }
+
+    /// Convert the odd to a fractional odd if it fits, or a decimal odd otherwise.
+    fn any_odd(&self) -> Result<AnyOdd, OddError> {
+        match self.to_fractional() {
+            Ok(fractional) => Ok(AnyOdd::Fractional(fractional)),
+            Err(_) => self.to_decimal().map(AnyOdd::Decimal),
+        }
+    }
+}
+
+impl Display for ExactOdd {
+    /// Display the odd as a fraction of its profit, like a fractional odd, e.g. `5/2`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let profit = self.profit_ratio();
+        write!(f, "{}/{}", profit.numer(), profit.denom())
+    }
+}
+
+impl FromStr for ExactOdd {
+    type Err = OddError;
+
+    /// Parse any odd, the same as [`AnyOdd`], and convert it without loss.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        input.parse::<AnyOdd>().map(Into::into)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::odds;
+    use test_case::test_case;
+
+    fn big(value: u128) -> BigUint {
+        value.into()
+    }
+
+    #[test_case("5/2", (7, 2))]
+    #[test_case("2.5", (5, 2))]
+    #[test_case("-110", (21, 11))]
+    #[test_case("+112.5", (17, 8))]
+    #[test_case("1.0", (1, 1))]
+    #[test_case("1.1", (11, 10))]
+    #[test_case("1e30", (1_000_000_000_000_000_000_000_000_000_000, 1))]
+    fn from_odd(input: &str, expected: (u128, u128)) {
+        let exact: ExactOdd = input.parse().unwrap();
+        assert_eq!(
+            (exact.payout_ratio().numer(), exact.payout_ratio().denom()),
+            (&big(expected.0), &big(expected.1))
+        );
+    }
+
+    #[test]
+    fn from_large_decimal() {
+        let exact = ExactOdd::from(Decimal::new(1e40).unwrap());
+        assert_eq!(exact.payout_ratio(), &Ratio::from_integer(big(10).pow(40)));
+    }
+
+    #[test_case((1, 0), Format::Fractional, Rule::ZeroDenominator)]
+    #[test_case((0, 1), Format::Decimal, Rule::DecimalBelowOne)]
+    #[test_case((9, 10), Format::Decimal, Rule::DecimalBelowOne)]
+    fn invalid(value: (u128, u128), format: Format, rule: Rule) {
+        let error = ExactOdd::new(big(value.0), big(value.1)).unwrap_err();
+        assert_eq!(error.format(), format);
+        assert_eq!(error.rule(), Some(rule));
+    }
+
+    #[test]
+    fn parlay() {
+        let parlay = ExactOdd::parlay([odds!(1 / 1), odds!(3 / 1), odds!(1 / 2)]);
+        assert_eq!(parlay.to_string(), "11/1");
+        assert_eq!(parlay.to_fractional().unwrap(), odds!(11 / 1));
+        assert_eq!(ExactOdd::parlay(Vec::<ExactOdd>::new()).to_string(), "0/1");
+    }
+
+    #[test]
+    fn parlay_overflow() {
+        let leg = ExactOdd::from(Fractional::new(u64::MAX - 1, 1).unwrap());
+        let parlay = ExactOdd::parlay([leg.clone(), leg.clone(), leg.clone()]);
+
+        assert_eq!(
+            parlay.payout_ratio().numer(),
+            &(big(u64::MAX as u128).pow(3))
+        );
+        assert!(parlay > leg.clone() * leg.clone());
+        assert_eq!(
+            parlay.to_fractional().unwrap_err().rule(),
+            Some(Rule::OutOfRange)
+        );
+        assert_eq!(parlay.any_odd().unwrap().format(), Format::Decimal);
+
+        let leg = ExactOdd::from(Fractional::new(10_000_000, 1).unwrap());
+        let parlay = ExactOdd::parlay([leg.clone(), leg.clone(), leg]);
+        assert_eq!(
+            parlay.any_odd().unwrap(),
+            AnyOdd::Decimal(Decimal::new(10_000_001f64.powi(3)).unwrap())
+        );
+    }
+
+    #[test]
+    fn book() {
+        let odds: Vec<ExactOdd> = ["2/1", "2/1", "2/1"]
+            .iter()
+            .map(|odd| odd.parse().unwrap())
+            .collect();
+        assert_eq!(ExactOdd::book(&odds), Ratio::from_integer(big(1)));
+
+        let odds = [ExactOdd::from(odds!(2.1)), ExactOdd::from(odds!(2.1))];
+        assert!(ExactOdd::book(&odds) < Ratio::from_integer(big(1)));
+    }
+
+    #[test]
+    fn cmp() {
+        let a = ExactOdd::from(odds!(-110));
+        let b = ExactOdd::from(odds!(10 / 11));
+        let c = ExactOdd::from(odds!(1.91));
+
+        assert_eq!(a, b);
+        assert!(a < c);
+        assert_eq!(a.probability(), Ratio::new(big(11), big(21)));
+    }
+
+    #[test]
+    fn dyn_odd() {
+        let exact = ExactOdd::from(odds!(+150));
+        let odd: &dyn DynOdd = &exact;
+
+        assert_eq!(odd.total_payout(10.0), 25.0);
+        assert_eq!(odd.implied_probability(), 0.4);
+        assert_eq!(odd.to_decimal().unwrap(), odds!(2.5));
+        assert_eq!(odd.any_odd().unwrap(), AnyOdd::from(odds!(3 / 2)));
+    }
+}
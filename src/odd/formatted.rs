@@ -0,0 +1,411 @@
+use core::fmt::{self, Display, Write};
+
+use crate::{buffer::Buffer, math};
+
+use super::{
+    locale::{write_decimal, write_localized},
+    AnyOdd, Format, Fractional, Locale, Odd,
+};
+
+/// What an odd is displayed as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Target {
+    /// The odd's own format.
+    Own,
+    /// Another format of odd.
+    Format(Format),
+    /// The implied probability of the odd, as a percentage.
+    Probability,
+}
+
+/// An odd displayed with configurable options.
+///
+/// Created by [`AnyOdd::formatted`]. By default, an odd is displayed the same as its
+/// [`Display`] implementation, e.g. without grouping thousands.
+///
+/// Example
+/// ```rust
+/// use wager::odd::{AnyOdd, Format, Locale};
+///
+/// let odd: AnyOdd = "-110".parse().unwrap();
+/// assert_eq!(odd.formatted().format(Format::Decimal).precision(2).to_string(), "1.91");
+/// assert_eq!(odd.formatted().format(Format::Fractional).reduce(false).to_string(), "100/110");
+/// assert_eq!(odd.formatted().probability().precision(1).to_string(), "52.4%");
+///
+/// let odd: AnyOdd = "2.0".parse().unwrap();
+/// assert_eq!(odd.formatted().format(Format::Moneyline).to_string(), "+100");
+/// assert_eq!(odd.formatted().format(Format::Moneyline).even_money_negative(true).to_string(), "-100");
+/// assert_eq!(odd.formatted().format(Format::Fractional).evens("EVS").to_string(), "EVS");
+///
+/// let odd: AnyOdd = "1001.5".parse().unwrap();
+/// assert_eq!(odd.formatted().locale(Locale::DE).to_string(), "1.001,5");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FormattedOdd<'a> {
+    odd: AnyOdd,
+    target: Target,
+    locale: Locale,
+    precision: Option<usize>,
+    trailing_zeros: bool,
+    evens: Option<&'a str>,
+    even_money_negative: bool,
+    reduce: bool,
+}
+
+impl<'a> FormattedOdd<'a> {
+    pub(crate) fn new(odd: AnyOdd) -> Self {
+        Self {
+            odd,
+            target: Target::Own,
+            locale: PLAIN,
+            precision: None,
+            trailing_zeros: true,
+            evens: None,
+            even_money_negative: false,
+            reduce: true,
+        }
+    }
+
+    /// Display the odd as another format.
+    ///
+    /// Odds that cannot be represented in the format, e.g. a decimal of `1.0` as a
+    /// moneyline, are displayed in their own format instead.
+    pub fn format(mut self, format: Format) -> Self {
+        self.target = Target::Format(format);
+        self
+    }
+
+    /// Display the implied probability of the odd as a percentage, e.g. `40%` for a
+    /// decimal of `2.5`.
+    pub fn probability(mut self) -> Self {
+        self.target = Target::Probability;
+        self
+    }
+
+    /// Display numbers with the separators of a locale. By default, numbers are written
+    /// like [`Display`] does, with a `.` and without grouping thousands.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Round decimal odds, moneyline odds and probabilities to a number of decimal
+    /// places. By default, decimal odds and probabilities use the shortest
+    /// representation that exactly identifies their value, and moneyline odds keep
+    /// their own decimal places or are otherwise rounded to whole numbers.
+    pub fn precision(mut self, places: usize) -> Self {
+        self.precision = Some(places);
+        self
+    }
+
+    /// Whether to keep trailing zeros after rounding to a [precision](Self::precision),
+    /// e.g. `2.10` rather than `2.1`. They are kept by default.
+    pub fn trailing_zeros(mut self, keep: bool) -> Self {
+        self.trailing_zeros = keep;
+        self
+    }
+
+    /// Display even money as a label, e.g. `EVS`, regardless of the format.
+    pub fn evens(mut self, label: &'a str) -> Self {
+        self.evens = Some(label);
+        self
+    }
+
+    /// Whether to display even money moneyline odds as `-100` rather than `+100`.
+    pub fn even_money_negative(mut self, negative: bool) -> Self {
+        self.even_money_negative = negative;
+        self
+    }
+
+    /// Whether to reduce fractional odds to their lowest terms, e.g. `10/11` rather
+    /// than `100/110`. They are reduced by default.
+    ///
+    /// Unreduced fractions are written over the natural denominator of the odd they
+    /// were converted from: `100` for moneyline odds and a power of ten for decimal
+    /// odds. Fractional odds are always stored in their lowest terms.
+    pub fn reduce(mut self, reduce: bool) -> Self {
+        self.reduce = reduce;
+        self
+    }
+
+    /// Get the numerator and denominator of the odd as a fraction, if it can be
+    /// represented as one.
+    fn fraction(&self) -> Option<(u64, u64)> {
+        match (self.odd, self.reduce) {
+            (AnyOdd::Fractional(fractional), _) => {
+                Some((fractional.numerator(), fractional.denominator()))
+            }
+            (AnyOdd::Decimal(decimal), true) => {
+                let fractional = Fractional::try_from(decimal).ok()?;
+                Some((fractional.numerator(), fractional.denominator()))
+            }
+            (AnyOdd::Decimal(decimal), false) => unreduced_decimal(decimal.value()),
+            (AnyOdd::Moneyline(moneyline), reduce) => {
+                let (numerator, denominator) = moneyline.profit();
+
+                if reduce {
+                    Some(math::simplify_fraction(numerator, denominator))
+                } else {
+                    Some((numerator, denominator))
+                }
+            }
+        }
+    }
+
+    /// Get the odd as a moneyline, if it can be represented as one.
+    fn moneyline(&self) -> Option<f64> {
+        if let AnyOdd::Moneyline(moneyline) = self.odd {
+            return Some(moneyline.value_f64());
+        }
+
+        let profit = self.odd.payout(1.0) - 1.0;
+        if profit <= 0.0 {
+            None
+        } else if profit >= 1.0 {
+            Some(100.0 * profit)
+        } else {
+            Some(-100.0 / profit)
+        }
+    }
+
+    fn write_fractional(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        (numerator, denominator): (u64, u64),
+    ) -> fmt::Result {
+        self.write_number(f, numerator)?;
+        f.write_char('/')?;
+        self.write_number(f, denominator)
+    }
+
+    fn write_moneyline(&self, f: &mut fmt::Formatter<'_>, value: f64) -> fmt::Result {
+        let negative = if self.odd.is_evens() {
+            self.even_money_negative
+        } else {
+            value < 0.0
+        };
+
+        // Moneyline odds keep their own decimal places by default.
+        let places = match self.odd {
+            AnyOdd::Moneyline(moneyline) => moneyline.places() as usize,
+            _ => 0,
+        };
+
+        f.write_char(if negative { '-' } else { '+' })?;
+        self.write_float(f, value.abs(), Some(self.precision.unwrap_or(places)))
+    }
+
+    fn write_probability(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let probability = 100.0 / self.odd.payout(1.0);
+
+        self.write_float(f, probability, self.precision)?;
+        f.write_char('%')
+    }
+
+    fn write_float(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        value: f64,
+        precision: Option<usize>,
+    ) -> fmt::Result {
+        let strip_zeros = !self.trailing_zeros;
+
+        match precision {
+            Some(precision) => write_localized(f, self.locale, strip_zeros, |w| {
+                write!(w, "{:.*}", precision, value)
+            }),
+            None => write_localized(f, self.locale, strip_zeros, |w| write!(w, "{}", value)),
+        }
+    }
+
+    fn write_number(&self, f: &mut fmt::Formatter<'_>, value: u64) -> fmt::Result {
+        write_localized(f, self.locale, false, |w| write!(w, "{}", value))
+    }
+}
+
+impl Display for FormattedOdd<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(label) = self.evens {
+            if self.target != Target::Probability && self.odd.is_evens() {
+                return f.write_str(label);
+            }
+        }
+
+        let format = match self.target {
+            Target::Own => self.odd.format(),
+            Target::Format(format) => format,
+            Target::Probability => return self.write_probability(f),
+        };
+
+        // Odds that cannot be represented in the target format fall back to their own.
+        match format {
+            Format::Decimal => write_decimal(
+                f,
+                self.locale,
+                self.odd.payout(1.0),
+                self.precision,
+                !self.trailing_zeros,
+            ),
+            Format::Fractional => match self.fraction() {
+                Some(fraction) => self.write_fractional(f, fraction),
+                None => self.odd.fmt(f),
+            },
+            Format::Moneyline => match self.moneyline() {
+                Some(value) => self.write_moneyline(f, value),
+                None => self.odd.fmt(f),
+            },
+        }
+    }
+}
+
+/// The notation of [`Display`], which doesn't group thousands.
+const PLAIN: Locale = Locale::new('.', None);
+
+/// Write the profit of a decimal odd as a fraction over a power of ten, using the
+/// shortest representation of its value, e.g. `91/100` for `1.91`.
+///
+/// Returns `None` if the profit is zero or the fraction does not fit.
+fn unreduced_decimal(value: f64) -> Option<(u64, u64)> {
+    let digits = Buffer::<64>::format(format_args!("{value}"))?;
+    let digits = digits.as_str();
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+    let denominator = 10u64.checked_pow(u32::try_from(fraction.len()).ok()?)?;
+    let integer: u64 = integer.parse().ok()?;
+    let fraction: u64 = if fraction.is_empty() {
+        0
+    } else {
+        fraction.parse().ok()?
+    };
+
+    let numerator = (integer - 1)
+        .checked_mul(denominator)?
+        .checked_add(fraction)?;
+
+    (numerator > 0).then_some((numerator, denominator))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn odd(input: &str) -> AnyOdd {
+        input.parse().unwrap()
+    }
+
+    #[test_case("5/2", "5/2")]
+    #[test_case("2.5", "2.5")]
+    #[test_case("-110", "-110")]
+    #[test_case("150.0", "150.0")]
+    #[test_case("1001.5", "1001.5")]
+    #[test_case("+1200", "+1200")]
+    #[test_case("1500/1", "1500/1")]
+    #[test_case("-112.5", "-112.5")]
+    fn own(input: &str, expected: &str) {
+        assert_eq!(odd(input).formatted().to_string(), expected);
+    }
+
+    #[test_case("5/2", Format::Decimal, "3.5")]
+    #[test_case("-110", Format::Decimal, "1.9090909090909092")]
+    #[test_case("+250", Format::Fractional, "5/2")]
+    #[test_case("-110", Format::Fractional, "10/11")]
+    #[test_case("1.5", Format::Fractional, "1/2")]
+    #[test_case("5/2", Format::Moneyline, "+250")]
+    #[test_case("1/2", Format::Moneyline, "-200")]
+    #[test_case("1.91", Format::Moneyline, "-110")]
+    #[test_case("1/1", Format::Moneyline, "+100")]
+    #[test_case("-112.5", Format::Moneyline, "-112.5")]
+    #[test_case("-112.5", Format::Fractional, "8/9")]
+    #[test_case("2.375", Format::Moneyline, "+138")]
+    #[test_case("1.0", Format::Moneyline, "1.0"; "unrepresentable_moneyline")]
+    #[test_case("1.0", Format::Fractional, "1.0"; "unrepresentable_fractional")]
+    #[test_case("+200", Format::Decimal, "3.0")]
+    #[test_case("1000/1", Format::Moneyline, "+100000")]
+    fn format(input: &str, format: Format, expected: &str) {
+        assert_eq!(odd(input).formatted().format(format).to_string(), expected);
+    }
+
+    #[test_case("-110", Format::Decimal, 2, true, "1.91")]
+    #[test_case("3.0", Format::Decimal, 2, true, "3.00")]
+    #[test_case("3.0", Format::Decimal, 2, false, "3")]
+    #[test_case("2.1", Format::Decimal, 3, false, "2.1")]
+    #[test_case("1.91", Format::Moneyline, 1, true, "-109.9")]
+    #[test_case("5/2", Format::Moneyline, 2, false, "+250")]
+    fn precision(input: &str, format: Format, places: usize, trailing_zeros: bool, expected: &str) {
+        let formatted = odd(input)
+            .formatted()
+            .format(format)
+            .precision(places)
+            .trailing_zeros(trailing_zeros);
+
+        assert_eq!(formatted.to_string(), expected);
+    }
+
+    #[test_case("+100", Format::Moneyline, false, "+100")]
+    #[test_case("-100", Format::Moneyline, true, "-100")]
+    #[test_case("2.0", Format::Moneyline, true, "-100")]
+    #[test_case("1/1", Format::Moneyline, false, "+100")]
+    #[test_case("+200", Format::Moneyline, true, "+200")]
+    fn even_money_negative(input: &str, format: Format, negative: bool, expected: &str) {
+        let formatted = odd(input)
+            .formatted()
+            .format(format)
+            .even_money_negative(negative);
+
+        assert_eq!(formatted.to_string(), expected);
+    }
+
+    #[test_case("1/1", "EVS")]
+    #[test_case("2.0", "EVS")]
+    #[test_case("-100", "EVS")]
+    #[test_case("2/1", "2/1")]
+    fn evens(input: &str, expected: &str) {
+        assert_eq!(odd(input).formatted().evens("EVS").to_string(), expected);
+    }
+
+    #[test]
+    fn evens_probability() {
+        let formatted = odd("2.0").formatted().evens("EVS").probability();
+        assert_eq!(formatted.to_string(), "50%");
+    }
+
+    #[test_case("-110", false, "100/110")]
+    #[test_case("-112.5", false, "1000/1125")]
+    #[test_case("-110", true, "10/11")]
+    #[test_case("+250", false, "250/100")]
+    #[test_case("1.91", false, "91/100")]
+    #[test_case("2.5", false, "15/10")]
+    #[test_case("3.0", false, "2/1")]
+    #[test_case("10/4", false, "5/2")]
+    fn reduce(input: &str, reduce: bool, expected: &str) {
+        let formatted = odd(input)
+            .formatted()
+            .format(Format::Fractional)
+            .reduce(reduce);
+
+        assert_eq!(formatted.to_string(), expected);
+    }
+
+    #[test_case("2.5", None, "40%")]
+    #[test_case("-110", Some(1), "52.4%")]
+    #[test_case("1/3", Some(2), "75.00%")]
+    fn probability(input: &str, precision: Option<usize>, expected: &str) {
+        let mut formatted = odd(input).formatted().probability();
+        if let Some(places) = precision {
+            formatted = formatted.precision(places);
+        }
+
+        assert_eq!(formatted.to_string(), expected);
+    }
+
+    #[test_case("1001.5", Format::Decimal, Locale::DE, "1.001,5")]
+    #[test_case("+1200", Format::Moneyline, Locale::EN, "+1,200")]
+    #[test_case("1500/1", Format::Fractional, Locale::EN, "1,500/1")]
+    #[test_case("150.0", Format::Decimal, Locale::EN, "150.0")]
+    fn locale(input: &str, format: Format, locale: Locale, expected: &str) {
+        let formatted = odd(input).formatted().format(format).locale(locale);
+        assert_eq!(formatted.to_string(), expected);
+    }
+}
@@ -1,10 +1,14 @@
-use std::{num::NonZeroU32, str::FromStr};
+use core::{
+    hash::{Hash, Hasher},
+    num::NonZeroU64,
+    str::FromStr,
+};
 
 use derive_more::Display;
 
-use crate::math;
+use crate::math::{self, Ratio};
 
-use super::{AnyOdd, Decimal, Moneyline, Odd, OddError};
+use super::{parse, AnyOdd, Decimal, Format, Moneyline, Odd, OddError, Rule};
 
 /// A fractional odd.
 ///
@@ -14,13 +18,20 @@ use super::{AnyOdd, Decimal, Moneyline, Odd, OddError};
 /// E.g. 4/1 means that for every unit staked, the bettor will profit 4 units.
 #[derive(Debug, Clone, Copy, Display)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "FractionalFields"))]
 #[display("{numerator}/{denominator}")]
 pub struct Fractional {
-    numerator: NonZeroU32,
-    denominator: NonZeroU32,
+    numerator: NonZeroU64,
+    denominator: NonZeroU64,
 }
 
 impl Fractional {
+    /// Even money, i.e. 1/1.
+    pub const EVENS: Self = Self {
+        numerator: NonZeroU64::MIN,
+        denominator: NonZeroU64::MIN,
+    };
+
     /// Create a new fractional odd from a `numerator` and `denominator`.
     ///   - Will be simplified to its lowest terms.
     ///   - Will error if the fraction is negative or zero.
@@ -36,10 +47,22 @@ impl Fractional {
     /// let fractional = Fractional::new(1, 0);
     /// assert!(fractional.is_err());
     /// ```
-    pub fn new(numerator: u32, denominator: u32) -> Result<Self, OddError> {
+    pub const fn new(numerator: u64, denominator: u64) -> Result<Self, OddError> {
+        if numerator == 0 {
+            return Err(OddError::invalid(Format::Fractional, Rule::ZeroNumerator));
+        }
+
+        if denominator == 0 {
+            return Err(OddError::invalid(Format::Fractional, Rule::ZeroDenominator));
+        }
+
         let (numerator, denominator) = math::simplify_fraction(numerator, denominator);
-        let numerator = numerator.try_into().map_err(|_| OddError::Invalid)?;
-        let denominator = denominator.try_into().map_err(|_| OddError::Invalid)?;
+        let Some(numerator) = NonZeroU64::new(numerator) else {
+            return Err(OddError::invalid(Format::Fractional, Rule::ZeroNumerator));
+        };
+        let Some(denominator) = NonZeroU64::new(denominator) else {
+            return Err(OddError::invalid(Format::Fractional, Rule::ZeroDenominator));
+        };
 
         Ok(Self {
             numerator,
@@ -47,15 +70,45 @@ impl Fractional {
         })
     }
 
+    /// Whether the fractional odd is even money.
+    pub fn is_evens(&self) -> bool {
+        *self == Self::EVENS
+    }
+
     /// Get the numerator of the fractional odd.
-    pub fn numerator(&self) -> u32 {
+    pub fn numerator(&self) -> u64 {
         self.numerator.get()
     }
 
     /// Get the denominator of the fractional odd.
-    pub fn denominator(&self) -> u32 {
+    pub fn denominator(&self) -> u64 {
         self.denominator.get()
     }
+
+    /// Get the exact ratio of the payout to the stake.
+    pub(crate) fn ratio(&self) -> Ratio {
+        let numerator = u128::from(self.numerator());
+        let denominator = u128::from(self.denominator());
+
+        Ratio::new(numerator + denominator, denominator)
+    }
+}
+
+/// The fields of a fractional odd as they are deserialized, before they are validated.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct FractionalFields {
+    numerator: u64,
+    denominator: u64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<FractionalFields> for Fractional {
+    type Error = OddError;
+
+    fn try_from(fields: FractionalFields) -> Result<Self, Self::Error> {
+        Self::new(fields.numerator, fields.denominator)
+    }
 }
 
 impl From<Fractional> for AnyOdd {
@@ -68,53 +121,54 @@ impl Odd for Fractional {
     fn payout(&self, stake: f64) -> f64 {
         stake * (1.0 + (self.numerator.get() as f64 / self.denominator.get() as f64))
     }
+
+    fn from_any(odd: AnyOdd) -> Result<Self, OddError> {
+        match odd {
+            AnyOdd::Fractional(odd) => Ok(odd),
+            AnyOdd::Decimal(odd) => Self::try_from(odd),
+            AnyOdd::Moneyline(odd) => Self::try_from(odd),
+        }
+    }
 }
 
 impl FromStr for Fractional {
     type Err = OddError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut parts = input.split('/');
-        let numerator = parts
-            .next()
-            .ok_or(OddError::ParseError)?
-            .trim()
-            .parse()
-            .map_err(|_| OddError::ParseError)?;
-        let denominator = parts
-            .next()
-            .ok_or(OddError::ParseError)?
-            .trim()
-            .parse()
-            .map_err(|_| OddError::ParseError)?;
+        let (numerator, denominator) = parse::fractional(input)
+            .map_err(|kind| OddError::from_kind(Format::Fractional, kind).with_input(input))?;
 
-        Self::new(numerator, denominator)
+        Self::new(numerator, denominator).map_err(|error| error.with_input(input))
     }
 }
 
 impl PartialEq for Fractional {
     fn eq(&self, other: &Self) -> bool {
-        let lcd = math::lcm(self.denominator(), other.denominator());
-        let numerator_a = self.numerator() * (lcd / self.denominator());
-        let numerator_b = other.numerator() * (lcd / other.denominator());
-
-        numerator_a == numerator_b
+        self.cmp(other).is_eq()
     }
 }
 
 impl Eq for Fractional {}
 
+impl Hash for Fractional {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Fractions are always stored in their lowest terms.
+        self.numerator.hash(state);
+        self.denominator.hash(state);
+    }
+}
+
 impl PartialOrd for Fractional {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
 impl Ord for Fractional {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let lcd = math::lcm(self.denominator(), other.denominator());
-        let numerator_a = self.numerator() * (lcd / self.denominator());
-        let numerator_b = other.numerator() * (lcd / other.denominator());
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        // Cross-multiply in a wider type so that the products can never overflow.
+        let numerator_a = u128::from(self.numerator()) * u128::from(other.denominator());
+        let numerator_b = u128::from(other.numerator()) * u128::from(self.denominator());
 
         numerator_a.cmp(&numerator_b)
     }
@@ -135,7 +189,10 @@ impl TryFrom<Decimal> for Fractional {
         let value = value.value();
         let (numerator, denominator) = math::rational_approximation(value - 1.0);
 
-        Self::new(numerator.unsigned_abs(), denominator.unsigned_abs())
+        Self::new(
+            numerator.unsigned_abs().into(),
+            denominator.unsigned_abs().into(),
+        )
     }
 }
 
@@ -143,11 +200,8 @@ impl TryFrom<Moneyline> for Fractional {
     type Error = OddError;
 
     fn try_from(value: Moneyline) -> Result<Self, Self::Error> {
-        if value.value() > 0 {
-            Self::new(value.value() as u32, 100)
-        } else {
-            Self::new(100, value.value().unsigned_abs() as u32)
-        }
+        let (numerator, denominator) = value.profit();
+        Self::new(numerator, denominator)
     }
 }
 
@@ -155,22 +209,30 @@ impl TryFrom<Moneyline> for Fractional {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
-    use std::cmp::Ordering;
+    use core::cmp::Ordering;
     use test_case::test_case;
 
     #[test_case((1, 2), (1, 2))]
     #[test_case((2, 4), (1, 2))]
     #[test_case((124, 56), (31, 14))]
-    fn valid(value: (u32, u32), expected: (u32, u32)) {
+    #[test_case((u64::MAX, 1), (u64::MAX, 1))]
+    fn valid(value: (u64, u64), expected: (u64, u64)) {
         let fractional = Fractional::new(value.0, value.1).unwrap();
         assert_eq!(fractional.numerator(), expected.0);
         assert_eq!(fractional.denominator(), expected.1);
     }
 
+    #[test]
+    fn evens() {
+        assert_eq!(Fractional::new(7, 7).unwrap(), Fractional::EVENS);
+        assert!(Fractional::EVENS.is_evens());
+        assert_eq!(Fractional::EVENS.to_string(), "1/1");
+    }
+
     #[test_case((0, 1))]
     #[test_case((1, 0))]
     #[test_case((0, 0))]
-    fn invalid(value: (u32, u32)) {
+    fn invalid(value: (u64, u64)) {
         let fractional = Fractional::new(value.0, value.1);
         assert!(fractional.is_err());
     }
@@ -178,7 +240,8 @@ mod tests {
     #[test_case((1, 2), (1, 2), true)]
     #[test_case((1, 2), (2, 4), true)]
     #[test_case((1, 2), (3, 6), true)]
-    fn eq(a: (u32, u32), b: (u32, u32), expected: bool) {
+    #[test_case((u64::MAX, u64::MAX - 1), (u64::MAX - 1, u64::MAX - 2), false)]
+    fn eq(a: (u64, u64), b: (u64, u64), expected: bool) {
         let a = Fractional::new(a.0, a.1).unwrap();
         let b = Fractional::new(b.0, b.1).unwrap();
         assert_eq!(a == b, expected);
@@ -189,7 +252,9 @@ mod tests {
     #[test_case((1, 2), (3, 6), Ordering::Equal)]
     #[test_case((1, 2), (2, 3), Ordering::Less)]
     #[test_case((2, 3), (1, 2), Ordering::Greater)]
-    fn cmp(a: (u32, u32), b: (u32, u32), expected: Ordering) {
+    #[test_case((u64::MAX, u64::MAX - 1), (u64::MAX - 1, u64::MAX - 2), Ordering::Less)]
+    #[test_case((u64::MAX, 1), (u64::MAX - 1, 1), Ordering::Greater)]
+    fn cmp(a: (u64, u64), b: (u64, u64), expected: Ordering) {
         let a = Fractional::new(a.0, a.1).unwrap();
         let b = Fractional::new(b.0, b.1).unwrap();
         assert_eq!(a.cmp(&b), expected);
@@ -198,8 +263,18 @@ mod tests {
     #[test_case((1, 2), 100.0, 150.0)]
     #[test_case((2, 1), 25.0, 75.0)]
     #[test_case((7, 9), 100.0, 177.77777777777777)]
-    fn payout(value: (u32, u32), stake: f64, expected: f64) {
+    fn payout(value: (u64, u64), stake: f64, expected: f64) {
         let fractional = Fractional::new(value.0, value.1).unwrap();
         assert_eq!(fractional.payout(stake), expected);
     }
+
+    #[test_case(i64::MIN, (100, 9223372036854775808))]
+    #[test_case(i64::MAX, (i64::MAX as u64, 100))]
+    fn from_moneyline(value: i64, expected: (u64, u64)) {
+        let fractional = Fractional::try_from(Moneyline::new(value).unwrap()).unwrap();
+        assert_eq!(
+            (fractional.numerator(), fractional.denominator()),
+            math::simplify_fraction(expected.0, expected.1)
+        );
+    }
 }
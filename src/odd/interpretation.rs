@@ -0,0 +1,183 @@
+use super::{AnyOdd, Decimal, Format, Fractional, Moneyline, OddError};
+
+/// A hint for choosing between the plausible interpretations of an input.
+///
+/// Many inputs are valid odds of more than one format, e.g. `"150"` is both a
+/// moneyline of +150 and a decimal of 150.0. A hint decides which of them is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FormatHint {
+    /// The input is known to be in the given format, so no other format is considered.
+    Only(Format),
+    /// Prefer the given format, falling back to the default order of the others.
+    Prefer(Format),
+    /// Prefer formats in the given order.
+    Order([Format; 3]),
+}
+
+impl FormatHint {
+    /// The order in which formats are preferred when parsing an [`AnyOdd`] with
+    /// [`FromStr`](core::str::FromStr).
+    pub const DEFAULT_ORDER: [Format; 3] = [Format::Moneyline, Format::Decimal, Format::Fractional];
+
+    /// Get the most preferred format, followed by the others to consider in order.
+    fn order(&self) -> (Format, impl Iterator<Item = Format> + '_) {
+        let (first, rest): (Format, &[Format]) = match self {
+            FormatHint::Only(format) => (*format, &[]),
+            FormatHint::Prefer(format) => (*format, &Self::DEFAULT_ORDER),
+            FormatHint::Order(order) => (order[0], &order[1..]),
+        };
+
+        (
+            first,
+            rest.iter().copied().filter(move |format| *format != first),
+        )
+    }
+}
+
+impl Default for FormatHint {
+    fn default() -> Self {
+        FormatHint::Order(Self::DEFAULT_ORDER)
+    }
+}
+
+/// Every plausible interpretation of an input as an odd.
+///
+/// Example
+/// ```rust
+/// use wager::odd::{AnyOdd, Format, FormatHint, Interpretations};
+///
+/// let interpretations = AnyOdd::interpret("150");
+/// assert!(interpretations.is_ambiguous());
+/// assert_eq!(interpretations.get(Format::Moneyline), Some("+150".parse().unwrap()));
+/// assert_eq!(interpretations.get(Format::Decimal), Some("150.0".parse().unwrap()));
+/// assert_eq!(interpretations.get(Format::Fractional), None);
+///
+/// let odd = interpretations.resolve(FormatHint::Prefer(Format::Decimal)).unwrap();
+/// assert!(matches!(odd, AnyOdd::Decimal(_)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interpretations {
+    fractional: Result<Fractional, OddError>,
+    decimal: Result<Decimal, OddError>,
+    moneyline: Result<Moneyline, OddError>,
+}
+
+impl Interpretations {
+    /// Interpret an input as every format of odd.
+    pub fn new(input: &str) -> Self {
+        Self {
+            fractional: input.parse(),
+            decimal: input.parse(),
+            moneyline: input.parse(),
+        }
+    }
+
+    /// Get the interpretation of the input as the given format, if it is valid.
+    pub fn get(&self, format: Format) -> Option<AnyOdd> {
+        self.result(format).ok()
+    }
+
+    /// Get the error from interpreting the input as the given format, if it is invalid.
+    pub fn error(&self, format: Format) -> Option<&OddError> {
+        self.result(format).err()
+    }
+
+    /// Get every valid interpretation of the input.
+    pub fn iter(&self) -> impl Iterator<Item = AnyOdd> + '_ {
+        Format::ALL
+            .into_iter()
+            .filter_map(|format| self.get(format))
+    }
+
+    /// Whether the input has more than one valid interpretation with different values.
+    pub fn is_ambiguous(&self) -> bool {
+        let mut odds = self.iter();
+
+        match odds.next() {
+            Some(first) => odds.any(|odd| odd != first),
+            None => false,
+        }
+    }
+
+    /// Choose an interpretation according to a hint.
+    ///
+    /// If none of the formats considered by the hint are valid, the most relevant error
+    /// is returned: a violated rule is preferred over a parsing failure, and otherwise
+    /// the parsing failure that progressed furthest into the input.
+    pub fn resolve(&self, hint: FormatHint) -> Result<AnyOdd, OddError> {
+        let relevance = |error: &OddError| (error.rule().is_some(), error.position());
+        let (first, rest) = hint.order();
+
+        let mut best = match self.result(first) {
+            Ok(odd) => return Ok(odd),
+            Err(error) => error,
+        };
+
+        for format in rest {
+            match self.result(format) {
+                Ok(odd) => return Ok(odd),
+                Err(error) if relevance(error) > relevance(best) => best = error,
+                Err(_) => {}
+            }
+        }
+
+        Err(best.clone())
+    }
+
+    fn result(&self, format: Format) -> Result<AnyOdd, &OddError> {
+        match format {
+            Format::Fractional => self.fractional.as_ref().map(|odd| (*odd).into()),
+            Format::Decimal => self.decimal.as_ref().map(|odd| (*odd).into()),
+            Format::Moneyline => self.moneyline.as_ref().map(|odd| (*odd).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("150", true)]
+    #[test_case("100", true)]
+    #[test_case("2", false)]
+    #[test_case("1/2", false)]
+    #[test_case("-110", false)]
+    #[test_case("1.5", false)]
+    #[test_case("abc", false)]
+    fn is_ambiguous(input: &str, expected: bool) {
+        assert_eq!(Interpretations::new(input).is_ambiguous(), expected);
+    }
+
+    #[test_case("150", FormatHint::default(), "+150")]
+    #[test_case("150", FormatHint::Prefer(Format::Decimal), "150.0")]
+    #[test_case("2", FormatHint::Prefer(Format::Moneyline), "2.0")]
+    #[test_case(
+        "150",
+        FormatHint::Order([Format::Fractional, Format::Decimal, Format::Moneyline]),
+        "150.0"
+    )]
+    #[test_case("5/2", FormatHint::Prefer(Format::Decimal), "5/2")]
+    fn resolve(input: &str, hint: FormatHint, expected: &str) {
+        let odd = Interpretations::new(input).resolve(hint).unwrap();
+        assert_eq!(odd.to_string(), expected);
+    }
+
+    #[test_case("2", Format::Moneyline)]
+    #[test_case("5/2", Format::Decimal)]
+    fn resolve_only(input: &str, format: Format) {
+        let error = Interpretations::new(input)
+            .resolve(FormatHint::Only(format))
+            .unwrap_err();
+        assert_eq!(error.format(), format);
+    }
+
+    #[test]
+    fn iter() {
+        let odds: Vec<AnyOdd> = Interpretations::new("200").iter().collect();
+        assert_eq!(odds.len(), 2);
+        assert!(matches!(odds[0], AnyOdd::Decimal(_)));
+        assert!(matches!(odds[1], AnyOdd::Moneyline(_)));
+    }
+}
@@ -0,0 +1,345 @@
+use core::fmt::{self, Display, Write};
+
+use crate::math;
+
+use super::Moneyline;
+
+/// The conventions that a locale uses for writing numbers.
+///
+/// Example
+/// ```rust
+/// use wager::odd::{Decimal, Locale, Moneyline};
+///
+/// let decimal = Decimal::parse_localized("1,91", Locale::DE).unwrap();
+/// assert_eq!(decimal.value(), 1.91);
+/// assert_eq!(decimal.localized(Locale::DE).to_string(), "1,91");
+///
+/// let moneyline = Moneyline::parse_localized("+1,200", Locale::EN).unwrap();
+/// assert_eq!(moneyline.value(), 1200);
+/// assert_eq!(moneyline.localized(Locale::EN).to_string(), "+1,200");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Locale {
+    decimal_separator: char,
+    thousands_separator: Option<char>,
+}
+
+impl Locale {
+    /// English conventions, e.g. `1,234.5`.
+    pub const EN: Locale = Locale::new('.', Some(','));
+
+    /// German conventions, which are shared by much of continental Europe, e.g. `1.234,5`.
+    pub const DE: Locale = Locale::new(',', Some('.'));
+
+    /// French conventions, e.g. `1 234,5` with a narrow no-break space.
+    pub const FR: Locale = Locale::new(',', Some('\u{202f}'));
+
+    /// Create a new locale from its decimal separator and, optionally, the separator
+    /// used to group thousands.
+    pub const fn new(decimal_separator: char, thousands_separator: Option<char>) -> Self {
+        Self {
+            decimal_separator,
+            thousands_separator,
+        }
+    }
+
+    /// Get the character that separates the integer and fractional parts of a number.
+    pub fn decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+
+    /// Get the character that separates groups of thousands, if any.
+    pub fn thousands_separator(&self) -> Option<char> {
+        self.thousands_separator
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::EN
+    }
+}
+
+/// A decimal odd displayed according to a [`Locale`].
+///
+/// Created by [`Decimal::localized`](super::Decimal::localized).
+///
+/// Example
+/// ```rust
+/// use wager::odd::{Decimal, Locale};
+///
+/// let decimal = Decimal::new(2.1).unwrap();
+/// assert_eq!(decimal.localized(Locale::EN).precision(2).to_string(), "2.10");
+/// assert_eq!(
+///     decimal
+///         .localized(Locale::DE)
+///         .precision(2)
+///         .trailing_zeros(false)
+///         .to_string(),
+///     "2,1"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocalizedDecimal {
+    value: f64,
+    locale: Locale,
+    precision: Option<usize>,
+    trailing_zeros: bool,
+}
+
+impl LocalizedDecimal {
+    pub(crate) fn new(value: f64, locale: Locale) -> Self {
+        Self {
+            value,
+            locale,
+            precision: None,
+            trailing_zeros: true,
+        }
+    }
+
+    /// Round to a number of decimal places. By default, the shortest representation
+    /// that exactly identifies the value is used, keeping a `.0` on whole numbers like
+    /// [`Display`] for [`Decimal`](super::Decimal) does.
+    pub fn precision(mut self, places: usize) -> Self {
+        self.precision = Some(places);
+        self
+    }
+
+    /// Whether to keep trailing zeros after rounding to a [precision](Self::precision),
+    /// e.g. `2.10` rather than `2.1`. They are kept by default.
+    pub fn trailing_zeros(mut self, keep: bool) -> Self {
+        self.trailing_zeros = keep;
+        self
+    }
+}
+
+impl Display for LocalizedDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_decimal(
+            f,
+            self.locale,
+            self.value,
+            self.precision,
+            !self.trailing_zeros,
+        )
+    }
+}
+
+/// A moneyline odd displayed according to a [`Locale`], e.g. `+1,200`.
+///
+/// Created by [`Moneyline::localized`](super::Moneyline::localized).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocalizedMoneyline {
+    moneyline: Moneyline,
+    locale: Locale,
+}
+
+impl LocalizedMoneyline {
+    pub(crate) fn new(moneyline: Moneyline, locale: Locale) -> Self {
+        Self { moneyline, locale }
+    }
+}
+
+impl Display for LocalizedMoneyline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char(if self.moneyline.is_positive() {
+            '+'
+        } else {
+            '-'
+        })?;
+        write_localized(f, self.locale, false, |w| self.moneyline.write_magnitude(w))
+    }
+}
+
+/// Write the value of a decimal odd with the separators of a locale, rounded to a
+/// precision if there is one.
+///
+/// Without a precision, whole numbers keep a `.0` so that they can't be mistaken for
+/// moneyline odds, and `strip_zeros` doesn't apply.
+pub(crate) fn write_decimal(
+    f: &mut fmt::Formatter<'_>,
+    locale: Locale,
+    value: f64,
+    precision: Option<usize>,
+    strip_zeros: bool,
+) -> fmt::Result {
+    match precision {
+        Some(precision) => write_localized(f, locale, strip_zeros, |w| {
+            write!(w, "{:.*}", precision, value)
+        }),
+        None if math::fract(value) == 0.0 => {
+            write_localized(f, locale, false, |w| write!(w, "{value:.1}"))
+        }
+        None => write_localized(f, locale, false, |w| write!(w, "{value}")),
+    }
+}
+
+/// Write a number with the separators of a locale, optionally stripping any zeros
+/// that trail its decimal point.
+///
+/// The number is written by `write` in the usual ASCII notation. It is written twice:
+/// once to count the digits of its integer part, which decides where the thousands
+/// separators go, and once to actually write it.
+pub(crate) fn write_localized(
+    f: &mut fmt::Formatter<'_>,
+    locale: Locale,
+    strip_zeros: bool,
+    write: impl Fn(&mut dyn Write) -> fmt::Result,
+) -> fmt::Result {
+    let mut counter = IntegerDigits::default();
+    write(&mut counter)?;
+
+    let mut writer = Localizer {
+        f,
+        locale,
+        strip_zeros,
+        integer_digits: counter.digits,
+        fraction: false,
+        separator: false,
+        pending_zeros: 0,
+    };
+    write(&mut writer)?;
+
+    writer.finish()
+}
+
+/// Counts the digits of the integer part of a number.
+#[derive(Default)]
+struct IntegerDigits {
+    digits: usize,
+    done: bool,
+}
+
+impl Write for IntegerDigits {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for character in s.chars() {
+            if character.is_ascii_digit() && !self.done {
+                self.digits += 1;
+            } else if character == '.' {
+                self.done = true;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Rewrites a number in ASCII notation with the separators of a locale.
+struct Localizer<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    locale: Locale,
+    strip_zeros: bool,
+    /// The number of integer digits that are still to be written.
+    integer_digits: usize,
+    /// Whether the decimal point has been read.
+    fraction: bool,
+    /// Whether the decimal separator has been written.
+    separator: bool,
+    /// The number of zeros after the decimal point that are yet to be written, because
+    /// they may turn out to be trailing.
+    pending_zeros: usize,
+}
+
+impl Localizer<'_, '_> {
+    /// Write the zeros after the decimal point that have been held back, preceded by
+    /// the decimal separator if it has not been written yet.
+    fn write_pending_zeros(&mut self) -> fmt::Result {
+        if self.pending_zeros > 0 && !self.separator {
+            self.f.write_char(self.locale.decimal_separator)?;
+            self.separator = true;
+        }
+
+        for _ in 0..self.pending_zeros {
+            self.f.write_char('0')?;
+        }
+        self.pending_zeros = 0;
+
+        Ok(())
+    }
+
+    fn finish(&mut self) -> fmt::Result {
+        if self.strip_zeros {
+            Ok(())
+        } else {
+            self.write_pending_zeros()
+        }
+    }
+}
+
+impl Write for Localizer<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for character in s.chars() {
+            match character {
+                '.' => self.fraction = true,
+                '0' if self.fraction => self.pending_zeros += 1,
+                digit if digit.is_ascii_digit() && self.fraction => {
+                    if !self.separator {
+                        self.f.write_char(self.locale.decimal_separator)?;
+                        self.separator = true;
+                    }
+                    self.write_pending_zeros()?;
+                    self.f.write_char(digit)?;
+                }
+                digit if digit.is_ascii_digit() => {
+                    self.f.write_char(digit)?;
+                    self.integer_digits = self.integer_digits.saturating_sub(1);
+
+                    if self.integer_digits > 0 && self.integer_digits % 3 == 0 {
+                        if let Some(separator) = self.locale.thousands_separator {
+                            self.f.write_char(separator)?;
+                        }
+                    }
+                }
+                other => self.f.write_char(other)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(2.1, Locale::EN, None, true, "2.1")]
+    #[test_case(2.1, Locale::EN, Some(2), true, "2.10")]
+    #[test_case(2.1, Locale::EN, Some(2), false, "2.1")]
+    #[test_case(2.0, Locale::EN, Some(2), false, "2")]
+    #[test_case(2.1, Locale::DE, Some(2), true, "2,10")]
+    #[test_case(1234.5, Locale::EN, None, true, "1,234.5")]
+    #[test_case(1234.5, Locale::DE, None, true, "1.234,5")]
+    #[test_case(1234.5, Locale::FR, None, true, "1\u{202f}234,5"; "fr")]
+    #[test_case(1234567.0, Locale::EN, Some(0), true, "1,234,567")]
+    #[test_case(1.005, Locale::EN, Some(1), false, "1")]
+    #[test_case(1.05, Locale::DE, Some(3), false, "1,05")]
+    #[test_case(100.0, Locale::EN, None, true, "100.0")]
+    #[test_case(1000.0, Locale::DE, None, false, "1.000,0")]
+    fn display_decimal(
+        value: f64,
+        locale: Locale,
+        precision: Option<usize>,
+        trailing_zeros: bool,
+        expected: &str,
+    ) {
+        let mut localized = LocalizedDecimal::new(value, locale).trailing_zeros(trailing_zeros);
+        if let Some(places) = precision {
+            localized = localized.precision(places);
+        }
+
+        assert_eq!(localized.to_string(), expected);
+    }
+
+    #[test_case("+1200", Locale::EN, "+1,200")]
+    #[test_case("-1500", Locale::DE, "-1.500")]
+    #[test_case("-110", Locale::EN, "-110")]
+    #[test_case("+100000", Locale::EN, "+100,000")]
+    #[test_case("-1500.25", Locale::DE, "-1.500,25")]
+    #[test_case("-9223372036854775808", Locale::EN, "-9,223,372,036,854,775,808")]
+    fn display_moneyline(input: &str, locale: Locale, expected: &str) {
+        let moneyline: Moneyline = input.parse().unwrap();
+        assert_eq!(moneyline.localized(locale).to_string(), expected);
+    }
+}
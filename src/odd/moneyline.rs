@@ -1,8 +1,14 @@
-use std::str::FromStr;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    str::FromStr,
+};
 
-use derive_more::Display;
+use crate::math::{self, Ratio};
 
-use super::{AnyOdd, Decimal, Fractional, Odd, OddError};
+use super::{
+    parse, AnyOdd, Decimal, Format, Fractional, Locale, LocalizedMoneyline, Odd, OddError, Rule,
+};
 
 /// A moneyline odd.
 ///
@@ -12,17 +18,32 @@ use super::{AnyOdd, Decimal, Fractional, Odd, OddError};
 /// E.g.
 /// +200 means that for every 100 units staked, the bettor will profit 200 units, while
 /// -200 means that for every 200 units staked, the bettor will profit 100 units.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Display)]
+///
+/// Values are usually whole numbers, but may have decimal places, e.g. -112.5. These are
+/// always written with an explicit sign, so that they are not mistaken for decimal odds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[display("{}{}", if value > &0i64 { "+" } else { "-" }, value.abs())]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "MoneylineFields", into = "MoneylineFields")
+)]
 pub struct Moneyline {
-    value: i64,
+    /// The value scaled by `10^places`, without trailing zeros when `places > 0`.
+    mantissa: i64,
+    places: u32,
 }
 
 impl Moneyline {
+    /// Even money, i.e. +100.
+    pub const EVENS: Self = Self {
+        mantissa: 100,
+        places: 0,
+    };
+
     /// Create a new moneyline odd from an integer.
     ///
-    /// This will error if the absolute value is less than 100.
+    /// This will error if the absolute value is less than 100. Both +100 and -100 are
+    /// even money, so -100 is normalized to [`Moneyline::EVENS`].
     ///
     /// Example
     /// ```rust
@@ -34,17 +55,261 @@ impl Moneyline {
     /// let moneyline = Moneyline::new(99);
     /// assert!(moneyline.is_err());
     /// ```
-    pub fn new(value: i64) -> Result<Self, OddError> {
-        if value.abs() < 100 {
-            return Err(OddError::Invalid);
+    pub const fn new(value: i64) -> Result<Self, OddError> {
+        Self::from_parts(value, 0)
+    }
+
+    /// Create a new moneyline odd with decimal places, from its value scaled by
+    /// `10^places`.
+    ///
+    /// This will error if the absolute value is less than 100.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::odd::Moneyline;
+    ///
+    /// let moneyline = Moneyline::from_parts(-1125, 1).unwrap();
+    /// assert_eq!(moneyline.value_f64(), -112.5);
+    /// assert_eq!(moneyline.to_string(), "-112.5");
+    ///
+    /// let moneyline = Moneyline::from_parts(-11250, 2).unwrap();
+    /// assert_eq!(moneyline.mantissa(), -1125);
+    /// assert_eq!(moneyline.places(), 1);
+    /// ```
+    pub const fn from_parts(mantissa: i64, places: u32) -> Result<Self, OddError> {
+        let (mut mantissa, mut places) = (mantissa, places);
+        while places > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            places -= 1;
+        }
+
+        // A scale that overflows is larger than any mantissa.
+        let below_100 = match 10u128.checked_pow(places) {
+            Some(scale) if scale <= u128::MAX / 100 => {
+                (mantissa.unsigned_abs() as u128) < scale * 100
+            }
+            _ => true,
+        };
+
+        if below_100 {
+            return Err(OddError::invalid(
+                Format::Moneyline,
+                Rule::MoneylineBelow100,
+            ));
         }
 
-        Ok(Self { value })
+        if mantissa == -100 && places == 0 {
+            return Ok(Self::EVENS);
+        }
+
+        Ok(Self { mantissa, places })
     }
 
-    /// Get the value of the moneyline odd.
+    /// Create a new moneyline odd by rounding a float to a number of decimal places.
+    ///
+    /// This will error if the rounded value is out of range, rather than saturating to
+    /// a different price.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::odd::Moneyline;
+    ///
+    /// let moneyline = Moneyline::round(-112.54, 1).unwrap();
+    /// assert_eq!(moneyline.to_string(), "-112.5");
+    ///
+    /// let moneyline = Moneyline::round(-112.54, 0).unwrap();
+    /// assert_eq!(moneyline.to_string(), "-113");
+    /// ```
+    pub fn round(value: f64, places: u32) -> Result<Self, OddError> {
+        let out_of_range = || OddError::invalid(Format::Moneyline, Rule::OutOfRange);
+
+        let scale = i32::try_from(places)
+            .map(|places| math::powi(10.0, places))
+            .map_err(|_| out_of_range())?;
+        let scaled = math::round(value * scale);
+
+        // `i64::MAX as f64` rounds up to 2^63, which is itself out of range.
+        if !(scaled >= i64::MIN as f64 && scaled < i64::MAX as f64) {
+            return Err(out_of_range());
+        }
+
+        Self::from_parts(scaled as i64, places)
+    }
+
+    /// Convert any odd to a moneyline odd, rounding it to a number of decimal places.
+    ///
+    /// The [`TryFrom`] conversions round to whole numbers.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::odd::{Fractional, Moneyline};
+    ///
+    /// let fractional = Fractional::new(3, 7).unwrap();
+    /// let moneyline = Moneyline::from_odd(fractional, 2).unwrap();
+    /// assert_eq!(moneyline.to_string(), "-233.33");
+    /// ```
+    pub fn from_odd(odd: impl Into<AnyOdd>, places: u32) -> Result<Self, OddError> {
+        let value = match odd.into() {
+            AnyOdd::Moneyline(moneyline) => moneyline.value_f64(),
+            AnyOdd::Decimal(decimal) => {
+                let value = decimal.value();
+                if value >= 2.0 {
+                    (value - 1.0) * 100.0
+                } else {
+                    -100.0 / (value - 1.0)
+                }
+            }
+            AnyOdd::Fractional(fractional) => {
+                let numerator = fractional.numerator() as f64;
+                let denominator = fractional.denominator() as f64;
+
+                if numerator >= denominator {
+                    numerator / denominator * 100.0
+                } else {
+                    -100.0 * denominator / numerator
+                }
+            }
+        };
+
+        Self::round(value, places)
+    }
+
+    /// Whether the moneyline odd is even money.
+    pub fn is_evens(&self) -> bool {
+        *self == Self::EVENS
+    }
+
+    /// Get the value of the moneyline odd, rounded half away from zero to a whole
+    /// number if it has decimal places.
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::odd::Moneyline;
+    ///
+    /// let moneyline = Moneyline::from_parts(-1125, 1).unwrap();
+    /// assert_eq!(moneyline.value(), -113);
+    /// assert_eq!(moneyline.value_f64(), -112.5);
+    /// ```
     pub fn value(&self) -> i64 {
-        self.value
+        if self.places == 0 {
+            return self.mantissa;
+        }
+
+        // Construction guarantees that the scale fits, as the magnitude is at least 100.
+        let scale = 10i64.pow(self.places);
+        let (whole, rest) = (self.mantissa / scale, self.mantissa % scale);
+        if rest.unsigned_abs() >= scale.unsigned_abs().div_ceil(2) {
+            whole + self.mantissa.signum()
+        } else {
+            whole
+        }
+    }
+
+    /// Get the value of the moneyline odd, including any decimal places.
+    pub fn value_f64(&self) -> f64 {
+        self.mantissa as f64 / math::powi(10.0, self.places as i32)
+    }
+
+    /// Get the value of the moneyline odd scaled by `10^places`, e.g. `-1125` for -112.5.
+    pub fn mantissa(&self) -> i64 {
+        self.mantissa
+    }
+
+    /// Get the number of decimal places in the value of the moneyline odd, e.g. `1` for
+    /// -112.5. This is `0` for whole numbers.
+    pub fn places(&self) -> u32 {
+        self.places
+    }
+
+    /// Parse a moneyline odd written with the separators of a locale, e.g. `"+1,200"` in
+    /// [`Locale::EN`].
+    ///
+    /// Example
+    /// ```rust
+    /// use wager::odd::{Locale, Moneyline};
+    ///
+    /// let moneyline = Moneyline::parse_localized("-1.500", Locale::DE).unwrap();
+    /// assert_eq!(moneyline.value(), -1500);
+    /// ```
+    pub fn parse_localized(input: &str, locale: Locale) -> Result<Self, OddError> {
+        let error = |kind| OddError::from_kind(Format::Moneyline, kind).with_input(input);
+
+        let rewritten = parse::delocalize(input, locale).map_err(error)?;
+        let (mantissa, places) =
+            parse::moneyline(rewritten.as_str()).map_err(|kind| error(rewritten.locate(kind)))?;
+
+        Self::from_parts(mantissa, places).map_err(|error| error.with_input(input))
+    }
+
+    /// Display the moneyline odd with the separators of a locale.
+    pub fn localized(&self, locale: Locale) -> LocalizedMoneyline {
+        LocalizedMoneyline::new(*self, locale)
+    }
+
+    /// Whether the value of the moneyline odd is positive.
+    pub(crate) fn is_positive(&self) -> bool {
+        self.mantissa > 0
+    }
+
+    /// Write the absolute value of the moneyline odd, without its sign.
+    pub(crate) fn write_magnitude(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        let magnitude = self.mantissa.unsigned_abs();
+        if self.places == 0 {
+            return write!(f, "{magnitude}");
+        }
+
+        // Construction guarantees that the scale fits, as the magnitude is at least 100.
+        let scale = 10u64.pow(self.places);
+        write!(
+            f,
+            "{}.{:0width$}",
+            magnitude / scale,
+            magnitude % scale,
+            width = self.places as usize
+        )
+    }
+
+    /// Get the profit for a stake as a fraction, which is not necessarily in its lowest
+    /// terms, e.g. `150/100` for +150 or `1000/1125` for -112.5.
+    pub(crate) fn profit(&self) -> (u64, u64) {
+        let magnitude = self.mantissa.unsigned_abs();
+        // Construction guarantees that there are at most 16 places.
+        let hundred = 100 * 10u64.pow(self.places);
+
+        if self.is_positive() {
+            (magnitude, hundred)
+        } else {
+            (hundred, magnitude)
+        }
+    }
+
+    /// Get the exact ratio of the payout to the stake.
+    pub(crate) fn ratio(&self) -> Ratio {
+        let (profit, stake) = self.profit();
+
+        Ratio::new(u128::from(profit) + u128::from(stake), u128::from(stake))
+    }
+}
+
+/// The fields of a moneyline odd as they are (de)serialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct MoneylineFields {
+    #[serde(with = "crate::serde::number")]
+    value: Moneyline,
+}
+
+#[cfg(feature = "serde")]
+impl From<MoneylineFields> for Moneyline {
+    fn from(fields: MoneylineFields) -> Self {
+        fields.value
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Moneyline> for MoneylineFields {
+    fn from(value: Moneyline) -> Self {
+        Self { value }
     }
 }
 
@@ -54,13 +319,44 @@ impl From<Moneyline> for AnyOdd {
     }
 }
 
+impl Display for Moneyline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.is_positive() { "+" } else { "-" })?;
+        self.write_magnitude(f)
+    }
+}
+
+impl PartialOrd for Moneyline {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Moneyline {
+    /// Order moneyline odds by their payout, which is not the same as ordering their
+    /// signed values, e.g. -100 and +100 are equal.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.ratio().cmp(&other.ratio())
+    }
+}
+
 impl Odd for Moneyline {
     /// Get the payout for a given stake.
     fn payout(&self, stake: f64) -> f64 {
-        if self.value > 0 {
-            stake * (1.0 + self.value as f64 / 100.0)
+        let value = self.value_f64();
+
+        if value > 0.0 {
+            stake * (1.0 + value / 100.0)
         } else {
-            stake * (1.0 + 100.0 / self.value.abs() as f64)
+            stake * (1.0 + 100.0 / value.abs())
+        }
+    }
+
+    fn from_any(odd: AnyOdd) -> Result<Self, OddError> {
+        match odd {
+            AnyOdd::Fractional(odd) => Self::try_from(odd),
+            AnyOdd::Decimal(odd) => Self::try_from(odd),
+            AnyOdd::Moneyline(odd) => Ok(odd),
         }
     }
 }
@@ -69,41 +365,28 @@ impl FromStr for Moneyline {
     type Err = OddError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let value = input.parse().map_err(|_| OddError::ParseError)?;
+        let (mantissa, places) = parse::moneyline(input)
+            .map_err(|kind| OddError::from_kind(Format::Moneyline, kind).with_input(input))?;
 
-        Self::new(value)
+        Self::from_parts(mantissa, places).map_err(|error| error.with_input(input))
     }
 }
 
 impl TryFrom<Decimal> for Moneyline {
     type Error = OddError;
 
+    /// Convert a decimal odd to a moneyline odd, rounded to a whole number.
     fn try_from(value: Decimal) -> Result<Self, Self::Error> {
-        let value = value.value();
-        let result = if value >= 2.0 {
-            (value - 1.0) * 100.0
-        } else {
-            -100.0 / (value - 1.0)
-        };
-
-        Self::new(result.round() as i64)
+        Self::from_odd(value, 0)
     }
 }
 
 impl TryFrom<Fractional> for Moneyline {
     type Error = OddError;
 
+    /// Convert a fractional odd to a moneyline odd, rounded to a whole number.
     fn try_from(value: Fractional) -> Result<Self, Self::Error> {
-        let numerator = value.numerator() as f64;
-        let denominator = value.denominator() as f64;
-
-        let result = if numerator >= denominator {
-            numerator / denominator * 100.0
-        } else {
-            -100.0 * denominator / numerator
-        };
-
-        Self::new(result.round() as i64)
+        Self::from_odd(value, 0)
     }
 }
 
@@ -111,13 +394,146 @@ impl TryFrom<Fractional> for Moneyline {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use core::cmp::Ordering;
     use test_case::test_case;
 
     #[test_case(100, 100)]
     #[test_case(-150, -150)]
+    #[test_case(i64::MIN, i64::MIN)]
     fn valid(value: i64, expected: i64) {
         let moneyline = Moneyline::new(value).unwrap();
-        assert_eq!(moneyline.value(), expected);
+        assert_eq!(moneyline.mantissa(), expected);
+        assert_eq!(moneyline.places(), 0);
+    }
+
+    #[test_case((-1125, 1), (-1125, 1), "-112.5")]
+    #[test_case((13750, 2), (1375, 1), "+137.5")]
+    #[test_case((-11000, 2), (-110, 0), "-110")]
+    #[test_case((10005, 2), (10005, 2), "+100.05")]
+    #[test_case((1_000_000_000_000_000_001, 16), (1_000_000_000_000_000_001, 16), "+100.0000000000000001")]
+    fn valid_parts(parts: (i64, u32), expected: (i64, u32), display: &str) {
+        let moneyline = Moneyline::from_parts(parts.0, parts.1).unwrap();
+        assert_eq!((moneyline.mantissa(), moneyline.places()), expected);
+        assert_eq!(moneyline.to_string(), display);
+        assert_eq!(display.parse::<Moneyline>().unwrap(), moneyline);
+    }
+
+    #[test_case((-9999, 2))]
+    #[test_case((i64::MAX, 17))]
+    #[test_case((i64::MIN, u32::MAX))]
+    #[test_case((0, 3))]
+    fn invalid_parts(parts: (i64, u32)) {
+        assert_eq!(
+            Moneyline::from_parts(parts.0, parts.1),
+            Err(OddError::invalid(
+                Format::Moneyline,
+                Rule::MoneylineBelow100
+            ))
+        );
+    }
+
+    #[test_case(-100; "negative")]
+    #[test_case(100; "positive")]
+    fn evens(value: i64) {
+        let moneyline = Moneyline::new(value).unwrap();
+        assert_eq!(moneyline, Moneyline::EVENS);
+        assert!(moneyline.is_evens());
+        assert_eq!(moneyline.to_string(), "+100");
+    }
+
+    #[test_case("-100"; "negative")]
+    #[test_case("-100.00"; "negative_places")]
+    #[test_case("+100"; "positive")]
+    #[test_case("evens")]
+    fn parse_evens(input: &str) {
+        assert_eq!(input.parse::<Moneyline>().unwrap(), Moneyline::EVENS);
+    }
+
+    #[test]
+    fn hash_evens() {
+        use std::collections::HashSet;
+
+        let odds: HashSet<Moneyline> = ["-100", "+100", "-100.0"]
+            .iter()
+            .map(|input| input.parse().unwrap())
+            .collect();
+        assert_eq!(odds.len(), 1);
+    }
+
+    #[test]
+    fn sort_by_payout() {
+        let mut odds: Vec<Moneyline> = ["+150", "-100", "-110", "+100", "-1000", "+100.5"]
+            .iter()
+            .map(|input| input.parse().unwrap())
+            .collect();
+        odds.sort();
+
+        let sorted: Vec<String> = odds.iter().map(ToString::to_string).collect();
+        assert_eq!(sorted, ["-1000", "-110", "+100", "+100", "+100.5", "+150"]);
+        assert!(odds
+            .windows(2)
+            .all(|pair| pair[0].payout(1.0) <= pair[1].payout(1.0)));
+    }
+
+    #[test_case("-112.5", "-112", Ordering::Less)]
+    #[test_case("-112.5", "-113", Ordering::Greater)]
+    #[test_case("+137.5", "+137.50", Ordering::Equal)]
+    #[test_case("+100.01", "+100", Ordering::Greater)]
+    #[test_case("-100.01", "+100", Ordering::Less)]
+    fn cmp(a: &str, b: &str, expected: Ordering) {
+        let a: Moneyline = a.parse().unwrap();
+        let b: Moneyline = b.parse().unwrap();
+        assert_eq!(a.cmp(&b), expected);
+    }
+
+    #[test_case("+137.5", 2.375)]
+    #[test_case("-112.5", 1.0 + 100.0 / 112.5)]
+    #[test_case("-125", 1.8)]
+    fn payout_places(input: &str, expected: f64) {
+        let moneyline: Moneyline = input.parse().unwrap();
+        assert_eq!(moneyline.payout(1.0), expected);
+    }
+
+    #[test]
+    fn exact_places() {
+        let moneyline: AnyOdd = "+137.5".parse().unwrap();
+        assert_eq!(moneyline, AnyOdd::Decimal(Decimal::new(2.375).unwrap()));
+        assert_eq!(
+            moneyline,
+            AnyOdd::Fractional(Fractional::new(11, 8).unwrap())
+        );
+
+        let moneyline: Moneyline = "-112.5".parse().unwrap();
+        assert_eq!(Fractional::try_from(moneyline), Fractional::new(8, 9));
+    }
+
+    #[test_case(-112.54, 1, "-112.5")]
+    #[test_case(-112.54, 0, "-113")]
+    #[test_case(137.499, 2, "+137.5")]
+    #[test_case(99.996, 2, "+100")]
+    fn round(value: f64, places: u32, expected: &str) {
+        assert_eq!(
+            Moneyline::round(value, places).unwrap().to_string(),
+            expected
+        );
+    }
+
+    #[test_case(f64::NAN, 0)]
+    #[test_case(1e30, 0)]
+    #[test_case(150.0, 20)]
+    fn round_out_of_range(value: f64, places: u32) {
+        assert!(Moneyline::round(value, places).is_err());
+    }
+
+    #[test_case(AnyOdd::Decimal(Decimal::new(1.888).unwrap()), 2, "-112.61")]
+    #[test_case(AnyOdd::Decimal(Decimal::new(2.375).unwrap()), 2, "+137.5")]
+    #[test_case(AnyOdd::Fractional(Fractional::new(3, 7).unwrap()), 1, "-233.3")]
+    #[test_case(AnyOdd::Moneyline(Moneyline::from_parts(-1125, 1).unwrap()), 0, "-113"; "half_away_from_zero")]
+    fn from_odd(odd: AnyOdd, places: u32, expected: &str) {
+        assert_eq!(
+            Moneyline::from_odd(odd, places).unwrap().to_string(),
+            expected
+        );
     }
 
     #[test_case(99)]
@@ -135,4 +551,59 @@ mod tests {
         let moneyline = Moneyline::new(value).unwrap();
         assert_eq!(moneyline.payout(stake), expected);
     }
+
+    #[test]
+    fn display_min() {
+        let moneyline = Moneyline::new(i64::MIN).unwrap();
+        assert_eq!(moneyline.to_string(), "-9223372036854775808");
+    }
+
+    #[test_case(Decimal::new(1e30).unwrap())]
+    #[test_case(Decimal::new(1.0).unwrap())]
+    fn from_decimal_out_of_range(value: Decimal) {
+        assert_eq!(
+            Moneyline::try_from(value),
+            Err(OddError::invalid(Format::Moneyline, Rule::OutOfRange))
+        );
+    }
+
+    #[test_case(Fractional::new(u64::MAX, 1).unwrap())]
+    #[test_case(Fractional::new(1, u64::MAX).unwrap())]
+    fn from_fractional_out_of_range(value: Fractional) {
+        assert_eq!(
+            Moneyline::try_from(value),
+            Err(OddError::invalid(Format::Moneyline, Rule::OutOfRange))
+        );
+    }
+
+    #[test_case("+1,200", Locale::EN, 1200)]
+    #[test_case("-1.500", Locale::DE, -1500)]
+    #[test_case("+1200", Locale::DE, 1200)]
+    #[test_case("-110", Locale::EN, -110)]
+    fn parse_localized(input: &str, locale: Locale, expected: i64) {
+        let moneyline = Moneyline::parse_localized(input, locale).unwrap();
+        assert_eq!(moneyline.value(), expected);
+    }
+
+    #[test]
+    fn parse_localized_places() {
+        let moneyline = Moneyline::parse_localized("-1.500,5", Locale::DE).unwrap();
+        assert_eq!(moneyline.value_f64(), -1500.5);
+    }
+
+    #[test_case((-1125, 1), -113)]
+    #[test_case((-1124, 1), -112)]
+    #[test_case((13750, 2), 138)]
+    #[test_case((10049, 2), 100)]
+    #[test_case((-200, 0), -200)]
+    fn value(parts: (i64, u32), expected: i64) {
+        let moneyline = Moneyline::from_parts(parts.0, parts.1).unwrap();
+        assert_eq!(moneyline.value(), expected);
+    }
+
+    #[test_case("+12,00", Locale::EN)]
+    #[test_case("+1,200", Locale::DE)]
+    fn parse_localized_invalid(input: &str, locale: Locale) {
+        assert!(Moneyline::parse_localized(input, locale).is_err());
+    }
 }
@@ -0,0 +1,570 @@
+//! Lenient parsing of the notations that odds are written in, reporting the byte
+//! position at which parsing failed.
+//!
+//! Besides the plain ASCII notations, this accepts the variations seen in scraped
+//! and hand-typed data:
+//!   - "evens", "even", "EVS" and "PK" for even money.
+//!   - "7-2" and "7 to 2" for fractional odds, as well as the unicode fraction slash
+//!     and vulgar fractions such as "2½".
+//!   - "7/2 against" and "7/2 on" or "2/7 on", where "on" means the shorter side of
+//!     the fraction is the profit.
+//!   - Full-width digits and symbols, and unicode minus signs and dashes.
+
+use crate::math;
+
+use super::{Locale, OddErrorKind, Rule};
+
+/// Words that mean even money, i.e. 1/1.
+const EVENS: [&str; 4] = ["evens", "even", "evs", "pk"];
+
+/// Parse a moneyline odd into its signed value scaled by `10^places`, along with the
+/// number of decimal places.
+///
+/// Decimal places are only accepted after an explicit sign, since e.g. "1.5" is a
+/// decimal odd.
+pub(crate) fn moneyline(input: &str) -> Result<(i64, u32), OddErrorKind> {
+    let mut scanner = Scanner::new(input);
+    scanner.skip_whitespace();
+
+    if scanner.eat_any_word(&EVENS) {
+        scanner.end()?;
+        return Ok((100, 0));
+    }
+
+    let negative = scanner.eat('-');
+    let signed = negative || scanner.eat('+');
+
+    let Some(magnitude) = scanner.integer()? else {
+        return Err(scanner.error());
+    };
+
+    let (magnitude, places) = if signed && scanner.eat('.') {
+        scanner.fraction(magnitude)?
+    } else {
+        (magnitude, 0)
+    };
+    scanner.end()?;
+
+    let magnitude = i128::from(magnitude);
+    let value = if negative { -magnitude } else { magnitude };
+
+    i64::try_from(value)
+        .map(|value| (value, places))
+        .map_err(|_| OddErrorKind::Invalid(Rule::OutOfRange))
+}
+
+/// Parse a fractional odd into its numerator and denominator, which are not
+/// necessarily in their lowest terms.
+pub(crate) fn fractional(input: &str) -> Result<(u64, u64), OddErrorKind> {
+    let mut scanner = Scanner::new(input);
+    scanner.skip_whitespace();
+
+    if scanner.eat_any_word(&EVENS) {
+        scanner.end()?;
+        return Ok((1, 1));
+    }
+
+    let numerator = component(&mut scanner)?;
+    scanner.skip_whitespace();
+
+    let denominator = if scanner.eat('/') || scanner.eat('-') || scanner.eat_word("to") {
+        scanner.skip_whitespace();
+        component(&mut scanner)?
+    } else if numerator.1 != 1 {
+        // A lone vulgar fraction such as "2½" is a complete odd.
+        (1, 1)
+    } else {
+        return Err(scanner.error());
+    };
+    scanner.skip_whitespace();
+
+    let on = scanner.eat_word("on");
+    if !on {
+        scanner.eat_word("against");
+    }
+    scanner.end()?;
+
+    let (numerator, denominator) = divide(numerator, denominator)?;
+
+    // "On" only reverses a fraction written as if it were against, e.g. "7/2 on" is
+    // 2/7, but "2/7 on" is already odds-on.
+    if on && numerator > denominator {
+        Ok((denominator, numerator))
+    } else {
+        Ok((numerator, denominator))
+    }
+}
+
+/// Parse a decimal odd.
+pub(crate) fn decimal(input: &str) -> Result<f64, OddErrorKind> {
+    let (trimmed, offset) = trim(input);
+    if trimmed.is_ascii() {
+        return float(trimmed, offset);
+    }
+
+    let mut normalized = Rewritten::new(input.len());
+    for (index, character) in trimmed.char_indices() {
+        normalized.push(normalize(character), offset + index)?;
+    }
+
+    float(normalized.as_str(), 0).map_err(|kind| normalized.locate(kind))
+}
+
+/// Rewrite an input that follows the conventions of a locale into the notation that
+/// the other parsing functions accept, by removing thousands separators and replacing
+/// the decimal separator with a `.`.
+///
+/// Thousands separators must be preceded by a digit and followed by exactly three, and
+/// can't follow the decimal separator. Surrounding whitespace is trimmed.
+pub(crate) fn delocalize(input: &str, locale: Locale) -> Result<Rewritten, OddErrorKind> {
+    let (trimmed, offset) = trim(input);
+    let mut rewritten = Rewritten::new(offset + trimmed.len());
+    let (mut after_digit, mut after_decimal) = (false, false);
+
+    for (index, character) in trimmed.char_indices() {
+        if Some(character) == locale.thousands_separator() {
+            let group = trimmed[index + character.len_utf8()..]
+                .chars()
+                .take_while(|character| normalize(*character).is_ascii_digit())
+                .count();
+
+            if !after_digit || after_decimal || group != 3 {
+                return Err(OddErrorKind::Parse {
+                    position: offset + index,
+                });
+            }
+
+            continue;
+        }
+
+        let character = if character == locale.decimal_separator() {
+            after_decimal = true;
+            '.'
+        } else {
+            character
+        };
+
+        after_digit = normalize(character).is_ascii_digit();
+        rewritten.push(character, offset + index)?;
+    }
+
+    Ok(rewritten)
+}
+
+/// Trim whitespace from `input`, returning the trimmed input along with the
+/// number of bytes trimmed from its start.
+fn trim(input: &str) -> (&str, usize) {
+    let trimmed = input.trim_start();
+    let offset = input.len() - trimmed.len();
+
+    (trimmed.trim_end(), offset)
+}
+
+/// Parse a float. The `offset` is the position of `input` within the original
+/// input, and is added to any reported position.
+fn float(input: &str, offset: usize) -> Result<f64, OddErrorKind> {
+    input.parse().map_err(|_| OddErrorKind::Parse {
+        position: offset + float_prefix_len(input),
+    })
+}
+
+/// Parse one side of a fractional odd: an integer, a vulgar fraction, or an
+/// integer followed by a vulgar fraction. Returns it as a fraction.
+fn component(scanner: &mut Scanner<'_>) -> Result<(u64, u64), OddErrorKind> {
+    let whole = scanner.integer()?;
+    let vulgar = scanner.peek().and_then(vulgar_fraction);
+    if vulgar.is_some() {
+        scanner.bump();
+    }
+
+    match (whole, vulgar) {
+        (None, None) => Err(scanner.error()),
+        (Some(whole), None) => Ok((whole, 1)),
+        (whole, Some((numerator, denominator))) => whole
+            .unwrap_or(0)
+            .checked_mul(denominator)
+            .and_then(|whole| whole.checked_add(numerator))
+            .map(|numerator| (numerator, denominator))
+            .ok_or(OddErrorKind::Invalid(Rule::OutOfRange)),
+    }
+}
+
+/// Divide one fraction by another.
+fn divide(dividend: (u64, u64), divisor: (u64, u64)) -> Result<(u64, u64), OddErrorKind> {
+    let numerator = u128::from(dividend.0) * u128::from(divisor.1);
+    let denominator = u128::from(dividend.1) * u128::from(divisor.0);
+
+    let gcd = match math::gcd(numerator, denominator) {
+        0 => 1,
+        gcd => gcd,
+    };
+
+    match (
+        u64::try_from(numerator / gcd),
+        u64::try_from(denominator / gcd),
+    ) {
+        (Ok(numerator), Ok(denominator)) => Ok((numerator, denominator)),
+        _ => Err(OddErrorKind::Invalid(Rule::OutOfRange)),
+    }
+}
+
+/// Map the variations of characters used in odds onto their ASCII equivalents.
+pub(super) fn normalize(character: char) -> char {
+    match character {
+        '０'..='９' => char::from(b'0' + (u32::from(character) - u32::from('０')) as u8),
+        '＋' => '+',
+        '－' | '−' | '‐' | '‑' | '–' => '-',
+        '．' => '.',
+        '／' | '⁄' | '∕' => '/',
+        _ => character,
+    }
+}
+
+/// Get the value of a unicode vulgar fraction character.
+fn vulgar_fraction(character: char) -> Option<(u64, u64)> {
+    let fraction = match character {
+        '½' => (1, 2),
+        '⅓' => (1, 3),
+        '⅔' => (2, 3),
+        '¼' => (1, 4),
+        '¾' => (3, 4),
+        '⅕' => (1, 5),
+        '⅖' => (2, 5),
+        '⅗' => (3, 5),
+        '⅘' => (4, 5),
+        '⅙' => (1, 6),
+        '⅚' => (5, 6),
+        '⅐' => (1, 7),
+        '⅛' => (1, 8),
+        '⅜' => (3, 8),
+        '⅝' => (5, 8),
+        '⅞' => (7, 8),
+        '⅑' => (1, 9),
+        '⅒' => (1, 10),
+        _ => return None,
+    };
+
+    Some(fraction)
+}
+
+/// Get the length of the longest prefix of `input` that looks like a float.
+fn float_prefix_len(input: &str) -> usize {
+    let bytes = input.as_bytes();
+    let digits = |from: usize| {
+        bytes[from..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count()
+    };
+
+    let mut len = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    len += digits(len);
+
+    if bytes.get(len) == Some(&b'.') {
+        len += 1;
+        len += digits(len);
+    }
+
+    if matches!(bytes.get(len), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(len + 1), Some(b'+' | b'-')));
+        let exponent = digits(len + 1 + sign);
+        if exponent > 0 {
+            len += 1 + sign + exponent;
+        }
+    }
+
+    len
+}
+
+/// A copy of an input that has been rewritten into a plainer notation, which
+/// remembers where each of its bytes came from so that errors refer to the input.
+///
+/// The copy is kept on the stack, and is plenty long enough for any sensible odd.
+pub(crate) struct Rewritten {
+    bytes: [u8; 64],
+    positions: [usize; 64],
+    len: usize,
+    end: usize,
+}
+
+impl Rewritten {
+    /// Create an empty copy of an input that is `end` bytes long.
+    fn new(end: usize) -> Self {
+        Self {
+            bytes: [0; 64],
+            positions: [0; 64],
+            len: 0,
+            end,
+        }
+    }
+
+    /// Append a character that came from `position` in the input, or error if the
+    /// copy is too long to be an odd.
+    fn push(&mut self, character: char, position: usize) -> Result<(), OddErrorKind> {
+        let len = self.len + character.len_utf8();
+        if len > self.bytes.len() {
+            return Err(OddErrorKind::Invalid(Rule::OutOfRange));
+        }
+
+        character.encode_utf8(&mut self.bytes[self.len..len]);
+        self.positions[self.len..len].fill(position);
+        self.len = len;
+
+        Ok(())
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        // Only whole characters are ever pushed, so the bytes are always valid.
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+
+    /// Map the position of an error in the copy back to its position in the input.
+    pub(crate) fn locate(&self, kind: OddErrorKind) -> OddErrorKind {
+        match kind {
+            OddErrorKind::Parse { position } => OddErrorKind::Parse {
+                position: if position < self.len {
+                    self.positions[position]
+                } else {
+                    self.end
+                },
+            },
+            kind => kind,
+        }
+    }
+}
+
+/// A cursor over an input that normalises characters as it reads them.
+struct Scanner<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn error(&self) -> OddErrorKind {
+        OddErrorKind::Parse {
+            position: self.position,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next().map(normalize)
+    }
+
+    fn bump(&mut self) {
+        if let Some(character) = self.rest().chars().next() {
+            self.position += character.len_utf8();
+        }
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        let matches = self.peek() == Some(expected);
+        if matches {
+            self.bump();
+        }
+
+        matches
+    }
+
+    /// Consume a case-insensitive word, provided it is not followed by more letters.
+    fn eat_word(&mut self, word: &str) -> bool {
+        let rest = self.rest();
+        let matches = rest
+            .get(..word.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(word))
+            && !rest[word.len()..]
+                .chars()
+                .next()
+                .is_some_and(char::is_alphabetic);
+
+        if matches {
+            self.position += word.len();
+        }
+
+        matches
+    }
+
+    fn eat_any_word(&mut self, words: &[&str]) -> bool {
+        words.iter().any(|word| self.eat_word(word))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    /// Consume a run of digits, if there is one.
+    fn integer(&mut self) -> Result<Option<u64>, OddErrorKind> {
+        let mut value = None;
+
+        while let Some(digit) = self.peek().and_then(|character| character.to_digit(10)) {
+            let next = value
+                .unwrap_or(0u64)
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(u64::from(digit)))
+                .ok_or(OddErrorKind::Invalid(Rule::OutOfRange))?;
+
+            value = Some(next);
+            self.bump();
+        }
+
+        Ok(value)
+    }
+
+    /// Consume the digits after a decimal point, appending them to an integer part.
+    /// Returns the combined digits and the number of digits after the point.
+    fn fraction(&mut self, integer: u64) -> Result<(u64, u32), OddErrorKind> {
+        let (mut value, mut places) = (integer, 0);
+
+        while let Some(digit) = self.peek().and_then(|character| character.to_digit(10)) {
+            value = value
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(u64::from(digit)))
+                .ok_or(OddErrorKind::Invalid(Rule::OutOfRange))?;
+
+            places += 1;
+            self.bump();
+        }
+
+        if places == 0 {
+            return Err(self.error());
+        }
+
+        Ok((value, places))
+    }
+
+    /// Ensure that nothing but whitespace remains.
+    fn end(mut self) -> Result<(), OddErrorKind> {
+        self.skip_whitespace();
+
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("", 0)]
+    #[test_case("+", 1)]
+    #[test_case("1.5", 3)]
+    #[test_case("-1.5e", 4)]
+    #[test_case("1.5e-3x", 6)]
+    #[test_case(".5.", 2)]
+    fn float_prefix(input: &str, expected: usize) {
+        assert_eq!(float_prefix_len(input), expected);
+    }
+
+    #[test_case("+150", 150)]
+    #[test_case(" -110 ", -110)]
+    #[test_case("150", 150; "unsigned")]
+    #[test_case("evens", 100)]
+    #[test_case("EVS", 100)]
+    #[test_case("Even", 100)]
+    #[test_case("PK", 100)]
+    #[test_case("−110", -110; "unicode minus")]
+    #[test_case("＋２５０", 250; "full width")]
+    #[test_case("-9223372036854775808", i64::MIN)]
+    fn valid_moneyline(input: &str, expected: i64) {
+        assert_eq!(moneyline(input).unwrap(), (expected, 0));
+    }
+
+    #[test_case("-112.5", (-1125, 1))]
+    #[test_case("+137.50", (13750, 2))]
+    #[test_case("−110.05", (-11005, 2); "unicode minus")]
+    fn valid_moneyline_places(input: &str, expected: (i64, u32)) {
+        assert_eq!(moneyline(input).unwrap(), expected);
+    }
+
+    #[test_case("evenss", OddErrorKind::Parse { position: 0 })]
+    #[test_case("+-110", OddErrorKind::Parse { position: 1 })]
+    #[test_case("9223372036854775808", OddErrorKind::Invalid(Rule::OutOfRange))]
+    #[test_case("112.5", OddErrorKind::Parse { position: 3 })]
+    #[test_case("-112.", OddErrorKind::Parse { position: 5 })]
+    #[test_case("-112.5.0", OddErrorKind::Parse { position: 6 })]
+    #[test_case("-9223372036854775808.1", OddErrorKind::Invalid(Rule::OutOfRange))]
+    fn invalid_moneyline(input: &str, expected: OddErrorKind) {
+        assert_eq!(moneyline(input), Err(expected));
+    }
+
+    #[test_case("7/2", (7, 2))]
+    #[test_case("7-2", (7, 2); "hyphen")]
+    #[test_case("7 - 2", (7, 2); "spaced hyphen")]
+    #[test_case("7 to 2", (7, 2))]
+    #[test_case("7 TO 2", (7, 2); "uppercase to")]
+    #[test_case("7⁄2", (7, 2); "fraction slash")]
+    #[test_case("7∕2", (7, 2); "division slash")]
+    #[test_case("７／２", (7, 2); "full width")]
+    #[test_case("½", (1, 2); "vulgar fraction")]
+    #[test_case("2½", (5, 2); "mixed vulgar fraction")]
+    #[test_case("2½/1", (5, 2); "mixed vulgar fraction numerator")]
+    #[test_case("11/4", (11, 4))]
+    #[test_case("7/2 against", (7, 2))]
+    #[test_case("2/7 on", (2, 7))]
+    #[test_case("7/2 on", (2, 7))]
+    #[test_case("2-1 on", (1, 2))]
+    #[test_case("evens", (1, 1))]
+    #[test_case("EVS", (1, 1))]
+    #[test_case("0/1", (0, 1))]
+    fn valid_fractional(input: &str, expected: (u64, u64)) {
+        assert_eq!(fractional(input).unwrap(), expected);
+    }
+
+    #[test_case("7", OddErrorKind::Parse { position: 1 })]
+    #[test_case("7 too 2", OddErrorKind::Parse { position: 2 })]
+    #[test_case("7/2 onn", OddErrorKind::Parse { position: 4 })]
+    #[test_case("7/2 on against", OddErrorKind::Parse { position: 7 })]
+    #[test_case("½½", OddErrorKind::Parse { position: 2 }; "repeated vulgar fraction")]
+    fn invalid_fractional(input: &str, expected: OddErrorKind) {
+        assert_eq!(fractional(input), Err(expected));
+    }
+
+    #[test_case("１.５", 1.5; "full width digits")]
+    #[test_case(" ２．２５ ", 2.25; "full width digits and point")]
+    fn valid_decimal(input: &str, expected: f64) {
+        assert_eq!(decimal(input).unwrap(), expected);
+    }
+
+    #[test_case("１.５x", OddErrorKind::Parse { position: 7 }; "trailing character")]
+    #[test_case("１.５½", OddErrorKind::Parse { position: 7 }; "trailing unicode character")]
+    fn invalid_decimal(input: &str, expected: OddErrorKind) {
+        assert_eq!(decimal(input), Err(expected));
+    }
+
+    #[test_case("1,91", Locale::DE, "1.91")]
+    #[test_case("1.234,5", Locale::DE, "1234.5")]
+    #[test_case("+1,200", Locale::EN, "+1200")]
+    #[test_case("-1,234,567", Locale::EN, "-1234567")]
+    #[test_case("1\u{202f}234,5", Locale::FR, "1234.5")]
+    #[test_case("  1,5  ", Locale::DE, "1.5")]
+    fn valid_delocalize(input: &str, locale: Locale, expected: &str) {
+        assert_eq!(delocalize(input, locale).unwrap().as_str(), expected);
+    }
+
+    #[test_case("1,91", Locale::EN, 1)]
+    #[test_case(",200", Locale::EN, 0)]
+    #[test_case("1.5", Locale::DE, 1)]
+    #[test_case("1,2345", Locale::EN, 1)]
+    #[test_case("1.234,567", Locale::EN, 5)]
+    #[test_case(" 1,5.000", Locale::DE, 4)]
+    fn invalid_delocalize(input: &str, locale: Locale, position: usize) {
+        assert_eq!(
+            delocalize(input, locale).err(),
+            Some(OddErrorKind::Parse { position })
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+
+use super::{AnyOdd, DynOdd, Odd, OddError};
+
+/// An odds format that isn't built in, e.g. Hong Kong or Malay odds, that can be
+/// added to a [`Registry`].
+pub trait CustomFormat {
+    /// Get the name of the format, which identifies it within a [`Registry`].
+    fn name(&self) -> &str;
+
+    /// Parse an odd in this format, or return `None` if the input isn't valid for it.
+    fn parse(&self, input: &str) -> Option<Box<dyn DynOdd>>;
+}
+
+/// A collection of [custom formats](CustomFormat) to parse odds with, in addition to
+/// the built in formats.
+///
+/// Example
+/// ```rust
+/// use wager::odd::{AnyOdd, CustomFormat, Decimal, DynOdd, OddError, Registry};
+///
+/// /// Hong Kong odds are the profit for a stake of one unit, e.g. `1.5`.
+/// #[derive(Debug)]
+/// struct HongKong(f64);
+///
+/// impl core::fmt::Display for HongKong {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "HK {}", self.0)
+///     }
+/// }
+///
+/// impl DynOdd for HongKong {
+///     fn total_payout(&self, stake: f64) -> f64 {
+///         stake * (1.0 + self.0)
+///     }
+///
+///     fn to_decimal(&self) -> Result<Decimal, OddError> {
+///         Decimal::new(1.0 + self.0)
+///     }
+/// }
+///
+/// struct HongKongFormat;
+///
+/// impl CustomFormat for HongKongFormat {
+///     fn name(&self) -> &str {
+///         "hong kong"
+///     }
+///
+///     fn parse(&self, input: &str) -> Option<Box<dyn DynOdd>> {
+///         let value = input.strip_prefix("HK ")?.parse().ok()?;
+///         Some(Box::new(HongKong(value)))
+///     }
+/// }
+///
+/// let mut registry = Registry::new();
+/// registry.register(HongKongFormat);
+///
+/// let odd = registry.parse("HK 1.5").unwrap();
+/// assert_eq!(odd, "3/2".parse::<AnyOdd>().unwrap());
+///
+/// let odd = registry.parse_as("hong kong", "HK 0.5").unwrap();
+/// assert_eq!(odd.implied_probability(), 1.0 / 1.5);
+/// ```
+#[derive(Default)]
+pub struct Registry {
+    formats: Vec<Box<dyn CustomFormat>>,
+}
+
+impl Registry {
+    /// Create a registry without any custom formats.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a custom format. Formats are tried in the order they were registered.
+    pub fn register(&mut self, format: impl CustomFormat + 'static) -> &mut Self {
+        self.formats.push(Box::new(format));
+        self
+    }
+
+    /// Get a custom format by name.
+    pub fn get(&self, name: &str) -> Option<&dyn CustomFormat> {
+        self.formats().find(|format| format.name() == name)
+    }
+
+    /// Iterate over the custom formats in the order they were registered.
+    pub fn formats(&self) -> impl Iterator<Item = &dyn CustomFormat> {
+        self.formats.iter().map(|format| format.as_ref())
+    }
+
+    /// Parse any odd, trying each custom format and then the built in formats (see
+    /// [`AnyOdd::from_str`]), so that a custom format can claim input that would also
+    /// be valid for a built in one.
+    ///
+    /// Odds of a custom format are converted with [`DynOdd::any_odd`], which keeps
+    /// them exact if they [are a ratio](DynOdd::to_fractional) but otherwise converts
+    /// them to decimal odds. If the input isn't valid for any format, the error from
+    /// parsing the built in formats is returned.
+    ///
+    /// [`AnyOdd::from_str`]: core::str::FromStr::from_str
+    pub fn parse(&self, input: &str) -> Result<AnyOdd, OddError> {
+        match self.parse_custom(input) {
+            Some(odd) => odd.any_odd(),
+            None => input.parse(),
+        }
+    }
+
+    /// Parse any odd like [`Registry::parse`], but without converting odds of a custom
+    /// format.
+    pub fn parse_dyn(&self, input: &str) -> Result<Box<dyn DynOdd>, OddError> {
+        match self.parse_custom(input) {
+            Some(odd) => Ok(odd),
+            None => Ok(Box::new(input.parse::<AnyOdd>()?)),
+        }
+    }
+
+    /// Parse an odd of the custom format with the given name, or return `None` if
+    /// there is no such format or the input isn't valid for it.
+    pub fn parse_as(&self, name: &str, input: &str) -> Option<Box<dyn DynOdd>> {
+        self.get(name)?.parse(input)
+    }
+
+    /// Parse any odd like [`Registry::parse`] and convert it to another type.
+    pub fn parse_to<T: Odd>(&self, input: &str) -> Result<T, OddError> {
+        self.parse(input)?.convert()
+    }
+
+    fn parse_custom(&self, input: &str) -> Option<Box<dyn DynOdd>> {
+        self.formats().find_map(|format| format.parse(input))
+    }
+}
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.formats().map(|format| format.name()))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::odd::{Decimal, Format, Fractional, Moneyline};
+    use test_case::test_case;
+
+    /// Malay odds are the profit for a stake of one unit when positive, or the
+    /// negative stake to win one unit when negative.
+    #[derive(Debug)]
+    struct Malay(f64);
+
+    impl fmt::Display for Malay {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} MY", self.0)
+        }
+    }
+
+    impl DynOdd for Malay {
+        fn total_payout(&self, stake: f64) -> f64 {
+            if self.0 > 0.0 {
+                stake * (1.0 + self.0)
+            } else {
+                stake * (1.0 - self.0.recip())
+            }
+        }
+
+        fn to_decimal(&self) -> Result<Decimal, OddError> {
+            Decimal::new(self.total_payout(1.0))
+        }
+    }
+
+    struct MalayFormat;
+
+    impl CustomFormat for MalayFormat {
+        fn name(&self) -> &str {
+            "malay"
+        }
+
+        fn parse(&self, input: &str) -> Option<Box<dyn DynOdd>> {
+            let value: f64 = input.strip_suffix(" MY")?.parse().ok()?;
+            (value != 0.0 && (-1.0..=1.0).contains(&value)).then(|| Box::new(Malay(value)) as _)
+        }
+    }
+
+    fn registry() -> Registry {
+        let mut registry = Registry::new();
+        registry.register(MalayFormat);
+        registry
+    }
+
+    #[test_case("0.5 MY", "1.5")]
+    #[test_case("-0.5 MY", "3.0")]
+    #[test_case("1 MY", "2.0")]
+    #[test_case("5/2", "5/2")]
+    #[test_case("-110", "-110")]
+    fn parse(input: &str, expected: &str) {
+        let odd = registry().parse(input).unwrap();
+        assert_eq!(odd.to_string(), expected);
+    }
+
+    #[test_case("2 MY")]
+    #[test_case("0 MY")]
+    #[test_case("abc")]
+    fn parse_invalid(input: &str) {
+        let error = registry().parse(input).unwrap_err();
+        assert_eq!(error, input.parse::<AnyOdd>().unwrap_err());
+    }
+
+    #[test]
+    fn parse_dyn() {
+        let registry = registry();
+
+        let odd = registry.parse_dyn("-0.25 MY").unwrap();
+        assert_eq!(odd.to_string(), "-0.25 MY");
+        assert_eq!(odd.total_payout(10.0), 50.0);
+
+        let odd = registry.parse_dyn("5/2").unwrap();
+        assert_eq!(odd.any_odd().unwrap().format(), Format::Fractional);
+    }
+
+    #[test]
+    fn parse_as() {
+        let registry = registry();
+
+        assert!(registry.parse_as("malay", "0.5 MY").is_some());
+        assert!(registry.parse_as("malay", "1.5").is_none());
+        assert!(registry.parse_as("indonesian", "0.5 MY").is_none());
+    }
+
+    #[test]
+    fn parse_to() {
+        let registry = registry();
+
+        assert_eq!(
+            registry.parse_to::<Fractional>("-0.5 MY").unwrap(),
+            Fractional::new(2, 1).unwrap()
+        );
+        assert_eq!(
+            registry.parse_to::<Moneyline>("0.5 MY").unwrap(),
+            Moneyline::new(-200).unwrap()
+        );
+    }
+
+    #[test]
+    fn custom_first() {
+        let mut registry = Registry::new();
+        registry.register(MalayFormat);
+        registry.register(AnythingFormat);
+
+        assert_eq!(registry.parse("2.5").unwrap().to_string(), "3.0");
+        assert_eq!(registry.parse_dyn("2.5").unwrap().to_string(), "3.0");
+        assert_eq!(registry.parse("0.5 MY").unwrap().to_string(), "1.5");
+        assert_eq!(registry.parse("x").unwrap().to_string(), "3.0");
+        assert_eq!(format!("{registry:?}"), r#"["malay", "anything"]"#);
+
+        assert_eq!("2.5".parse::<AnyOdd>().unwrap().to_string(), "2.5");
+    }
+
+    #[test]
+    fn exact_ratio() {
+        let mut registry = Registry::new();
+        registry.register(AgainstFormat);
+
+        let odd = registry.parse("7 to 9").unwrap();
+        assert_eq!(odd, AnyOdd::Fractional(Fractional::new(7, 9).unwrap()));
+        assert_eq!(odd.to_string(), "7/9");
+    }
+
+    /// Odds written as words, e.g. `7 to 9`, which are always exactly a ratio.
+    #[derive(Debug)]
+    struct Against(u64, u64);
+
+    impl fmt::Display for Against {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} to {}", self.0, self.1)
+        }
+    }
+
+    impl DynOdd for Against {
+        fn total_payout(&self, stake: f64) -> f64 {
+            stake * (1.0 + self.0 as f64 / self.1 as f64)
+        }
+
+        fn to_decimal(&self) -> Result<Decimal, OddError> {
+            Decimal::new(self.total_payout(1.0))
+        }
+
+        fn to_fractional(&self) -> Option<Fractional> {
+            Fractional::new(self.0, self.1).ok()
+        }
+    }
+
+    struct AgainstFormat;
+
+    impl CustomFormat for AgainstFormat {
+        fn name(&self) -> &str {
+            "against"
+        }
+
+        fn parse(&self, input: &str) -> Option<Box<dyn DynOdd>> {
+            let (numerator, denominator) = input.split_once(" to ")?;
+            let odd = Against(numerator.parse().ok()?, denominator.parse().ok()?);
+            Some(Box::new(odd))
+        }
+    }
+
+    struct AnythingFormat;
+
+    impl CustomFormat for AnythingFormat {
+        fn name(&self) -> &str {
+            "anything"
+        }
+
+        fn parse(&self, _: &str) -> Option<Box<dyn DynOdd>> {
+            Some(Box::new(Decimal::new(3.0).unwrap()))
+        }
+    }
+}
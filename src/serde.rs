@@ -0,0 +1,403 @@
+//! Alternative serde representations of odds.
+//!
+//! By default, odds are (de)serialized as structs of their fields, e.g. `{"value": 2.5}`
+//! for a decimal odd, and an [`AnyOdd`](crate::odd::AnyOdd) is tagged with its format,
+//! e.g. `{"decimal": 2.5}`. The modules here can be used with `#[serde(with = "...")]` to
+//! (de)serialize them in other ways. Odds are always validated when deserialized.
+//!
+//! Moneyline odds are numbers of varying type in human readable formats, e.g. `-110` or
+//! `-112.5`, but their mantissa and places in others, e.g. bincode.
+//!
+//! Example
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//! use wager::odd::{Decimal, Fractional, Moneyline};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Prices {
+//!     #[serde(with = "wager::serde::string")]
+//!     fractional: Fractional,
+//!     #[serde(with = "wager::serde::number")]
+//!     decimal: Decimal,
+//!     #[serde(with = "wager::serde::number")]
+//!     moneyline: Moneyline,
+//! }
+//!
+//! let json = r#"{"fractional":"5/2","decimal":3.5,"moneyline":250}"#;
+//! let prices: Prices = serde_json::from_str(json).unwrap();
+//! assert_eq!(prices.fractional, Fractional::new(5, 2).unwrap());
+//! assert_eq!(serde_json::to_string(&prices).unwrap(), json);
+//!
+//! let json = r#"{"fractional":"5/2","decimal":0.5,"moneyline":250}"#;
+//! assert!(serde_json::from_str::<Prices>(json).is_err());
+//! ```
+
+use crate::odd::{Decimal, Moneyline};
+
+/// (De)serialize odds as strings in their usual notation, e.g. `"5/2"`, `"2.5"` or `"+150"`.
+///
+/// Strings are parsed leniently, the same as [`FromStr`](core::str::FromStr). This also
+/// supports [`AnyOdd`](crate::odd::AnyOdd), whose notation identifies its format.
+pub mod string {
+    use core::{fmt, fmt::Display, marker::PhantomData, str::FromStr};
+
+    use ::serde::{de, Deserializer, Serializer};
+
+    /// Serialize an odd as a string.
+    pub fn serialize<T, S>(odd: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(odd)
+    }
+
+    /// Deserialize an odd from a string.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StringVisitor(PhantomData))
+    }
+
+    struct StringVisitor<T>(PhantomData<T>);
+
+    impl<T> de::Visitor<'_> for StringVisitor<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("an odd as a string")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            value.parse().map_err(E::custom)
+        }
+    }
+}
+
+/// (De)serialize odds as plain numbers, e.g. `2.5` for a decimal odd or `150` for a
+/// moneyline odd.
+///
+/// Fractional odds have no representation as a single number, so only the types that
+/// implement [`Number`] are supported.
+pub mod number {
+    use ::serde::{Deserializer, Serializer};
+
+    use super::Number;
+
+    /// Serialize an odd as a number.
+    pub fn serialize<T, S>(odd: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Number,
+        S: Serializer,
+    {
+        odd.serialize_number(serializer)
+    }
+
+    /// Deserialize an odd from a number.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Number,
+        D: Deserializer<'de>,
+    {
+        T::deserialize_number(deserializer)
+    }
+}
+
+/// An odd that can be represented as a single number. See [`number`].
+pub trait Number: sealed::Sealed {}
+
+impl Number for Decimal {}
+impl Number for Moneyline {}
+
+mod sealed {
+    use core::fmt;
+
+    use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{
+        buffer::Buffer,
+        odd::{Decimal, Moneyline},
+    };
+
+    pub trait Sealed: Sized {
+        fn serialize_number<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+        fn deserialize_number<'de, D: Deserializer<'de>>(deserializer: D)
+            -> Result<Self, D::Error>;
+    }
+
+    impl Sealed for Decimal {
+        fn serialize_number<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.value().serialize(serializer)
+        }
+
+        fn deserialize_number<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            Decimal::new(f64::deserialize(deserializer)?).map_err(de::Error::custom)
+        }
+    }
+
+    impl Sealed for Moneyline {
+        /// Whole moneyline odds are serialized as integers, and others as floats.
+        ///
+        /// Formats that aren't human readable, e.g. bincode, don't say which of these
+        /// they contain, so they get the mantissa and places instead.
+        fn serialize_number<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if !serializer.is_human_readable() {
+                (self.mantissa(), self.places()).serialize(serializer)
+            } else if self.places() == 0 {
+                self.mantissa().serialize(serializer)
+            } else {
+                self.value_f64().serialize(serializer)
+            }
+        }
+
+        fn deserialize_number<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            if !deserializer.is_human_readable() {
+                let (mantissa, places) = <(i64, u32)>::deserialize(deserializer)?;
+                return Moneyline::from_parts(mantissa, places).map_err(de::Error::custom);
+            }
+
+            deserializer.deserialize_any(MoneylineVisitor)
+        }
+    }
+
+    struct MoneylineVisitor;
+
+    impl de::Visitor<'_> for MoneylineVisitor {
+        type Value = Moneyline;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a moneyline odd as a number")
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            Moneyline::new(value).map_err(E::custom)
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            let value = i64::try_from(value)
+                .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(value), &self))?;
+
+            self.visit_i64(value)
+        }
+
+        /// Floats are read with the decimal places of their shortest representation,
+        /// e.g. `-112.5` rather than the binary approximation of it.
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            if !value.is_finite() {
+                return Err(E::invalid_value(de::Unexpected::Float(value), &self));
+            }
+
+            // Floats that don't fit are far outside the range of a moneyline odd.
+            Buffer::<64>::format(format_args!("{value:+}"))
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Float(value), &self))?
+                .as_str()
+                .parse()
+                .map_err(E::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+    use test_case::test_case;
+
+    use crate::odd::{AnyOdd, Fractional, Moneyline};
+
+    use super::*;
+
+    #[test_case(r#"{"value":2.5}"#)]
+    #[test_case(r#"{"value":1}"#)]
+    fn decimal(json: &str) {
+        let decimal: Decimal = serde_json::from_str(json).unwrap();
+        assert_eq!(decimal, Decimal::new(decimal.value()).unwrap());
+    }
+
+    #[test_case(r#"{"value":0.5}"#)]
+    #[test_case(r#"{"value":-2.0}"#)]
+    #[test_case(r#"{}"#)]
+    fn invalid_decimal(json: &str) {
+        assert!(serde_json::from_str::<Decimal>(json).is_err());
+    }
+
+    #[test_case(r#"{"value":50}"#)]
+    #[test_case(r#"{"value":-99}"#)]
+    #[test_case(r#"{"value":0}"#)]
+    #[test_case(r#"{"value":"-110"}"#)]
+    #[test_case(r#"{"value":18446744073709551615}"#)]
+    fn invalid_moneyline(json: &str) {
+        assert!(serde_json::from_str::<Moneyline>(json).is_err());
+    }
+
+    #[test]
+    fn fractional_is_simplified() {
+        let fractional: Fractional =
+            serde_json::from_str(r#"{"numerator":10,"denominator":4}"#).unwrap();
+        assert_eq!(fractional.numerator(), 5);
+        assert_eq!(fractional.denominator(), 2);
+    }
+
+    #[test_case(r#"{"numerator":0,"denominator":4}"#)]
+    #[test_case(r#"{"numerator":1,"denominator":0}"#)]
+    fn invalid_fractional(json: &str) {
+        assert!(serde_json::from_str::<Fractional>(json).is_err());
+    }
+
+    #[test_case(r#"{"value":-110}"#, "-110")]
+    #[test_case(r#"{"value":-112.5}"#, "-112.5")]
+    #[test_case(r#"{"value":137.5}"#, "+137.5")]
+    #[test_case(r#"{"value":-110.0}"#, "-110"; "whole_float")]
+    fn moneyline(json: &str, expected: &str) {
+        let moneyline: Moneyline = serde_json::from_str(json).unwrap();
+        assert_eq!(moneyline.to_string(), expected);
+    }
+
+    #[test]
+    fn round_trip_places() {
+        let moneyline = Moneyline::from_parts(-1125, 1).unwrap();
+        let json = serde_json::to_string(&moneyline).unwrap();
+        assert_eq!(json, r#"{"value":-112.5}"#);
+        assert_eq!(serde_json::from_str::<Moneyline>(&json).unwrap(), moneyline);
+    }
+
+    #[test]
+    fn round_trip() {
+        let moneyline = Moneyline::new(-110).unwrap();
+        let json = serde_json::to_string(&moneyline).unwrap();
+        assert_eq!(json, r#"{"value":-110}"#);
+        assert_eq!(serde_json::from_str::<Moneyline>(&json).unwrap(), moneyline);
+    }
+
+    #[test_case(AnyOdd::Moneyline(Moneyline::new(-110).unwrap()))]
+    #[test_case(AnyOdd::Moneyline(Moneyline::from_parts(-1125, 1).unwrap()))]
+    #[test_case(AnyOdd::Decimal(Decimal::new(2.5).unwrap()))]
+    #[test_case(AnyOdd::Fractional(Fractional::new(5, 2).unwrap()))]
+    fn round_trip_binary(odd: AnyOdd) {
+        let bytes = bincode::serialize(&odd).unwrap();
+        assert_eq!(bincode::deserialize::<AnyOdd>(&bytes).unwrap(), odd);
+        assert_eq!(
+            bincode::deserialize::<AnyOdd>(&bytes).unwrap().format(),
+            odd.format()
+        );
+    }
+
+    #[test]
+    fn invalid_moneyline_binary() {
+        let bytes = bincode::serialize(&(-99i64, 0u32)).unwrap();
+        assert!(bincode::deserialize::<Moneyline>(&bytes).is_err());
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Strings {
+        #[serde(with = "string")]
+        fractional: Fractional,
+        #[serde(with = "string")]
+        decimal: Decimal,
+        #[serde(with = "string")]
+        moneyline: Moneyline,
+    }
+
+    #[test]
+    fn strings() {
+        let json = r#"{"fractional":"5/2","decimal":"2.5","moneyline":"+150"}"#;
+        let strings: Strings = serde_json::from_str(json).unwrap();
+        assert_eq!(strings.fractional, Fractional::new(5, 2).unwrap());
+        assert_eq!(strings.decimal, Decimal::new(2.5).unwrap());
+        assert_eq!(strings.moneyline, Moneyline::new(150).unwrap());
+        assert_eq!(serde_json::to_string(&strings).unwrap(), json);
+    }
+
+    #[test_case(r#"{"fractional":"0/2","decimal":"2.5","moneyline":"+150"}"#)]
+    #[test_case(r#"{"fractional":"5/2","decimal":"0.5","moneyline":"+150"}"#)]
+    #[test_case(r#"{"fractional":"5/2","decimal":"2.5","moneyline":"+50"}"#)]
+    #[test_case(r#"{"fractional":"5/2","decimal":2.5,"moneyline":"+150"}"#)]
+    fn invalid_strings(json: &str) {
+        assert!(serde_json::from_str::<Strings>(json).is_err());
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Numbers {
+        #[serde(with = "number")]
+        decimal: Decimal,
+        #[serde(with = "number")]
+        moneyline: Moneyline,
+    }
+
+    #[test]
+    fn numbers() {
+        let json = r#"{"decimal":2.5,"moneyline":-110}"#;
+        let numbers: Numbers = serde_json::from_str(json).unwrap();
+        assert_eq!(numbers.decimal, Decimal::new(2.5).unwrap());
+        assert_eq!(numbers.moneyline, Moneyline::new(-110).unwrap());
+        assert_eq!(serde_json::to_string(&numbers).unwrap(), json);
+    }
+
+    #[test_case(r#"{"decimal":0.5,"moneyline":-110}"#)]
+    #[test_case(r#"{"decimal":2.5,"moneyline":-10}"#)]
+    #[test_case(r#"{"decimal":2.5,"moneyline":-99.5}"#)]
+    #[test_case(r#"{"decimal":"2.5","moneyline":-110}"#)]
+    fn invalid_numbers(json: &str) {
+        assert!(serde_json::from_str::<Numbers>(json).is_err());
+    }
+
+    #[test_case("5/2", r#"{"fractional":"5/2"}"#)]
+    #[test_case("2.5", r#"{"decimal":2.5}"#)]
+    #[test_case("150.0", r#"{"decimal":150.0}"#)]
+    #[test_case("-110", r#"{"moneyline":-110}"#)]
+    #[test_case("+100", r#"{"moneyline":100}"#)]
+    fn any_odd_tagged(input: &str, expected: &str) {
+        let odd: AnyOdd = input.parse().unwrap();
+        let json = serde_json::to_string(&odd).unwrap();
+        assert_eq!(json, expected);
+
+        let deserialized: AnyOdd = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, odd);
+        assert_eq!(deserialized.format(), odd.format());
+    }
+
+    #[test_case(r#"{"fractional":"0/2"}"#)]
+    #[test_case(r#"{"decimal":0.5}"#)]
+    #[test_case(r#"{"moneyline":-99}"#)]
+    #[test_case(r#"{"moneyline":"-110"}"#)]
+    #[test_case(r#"{"american":-110}"#)]
+    fn invalid_any_odd_tagged(json: &str) {
+        assert!(serde_json::from_str::<AnyOdd>(json).is_err());
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct AnyString(#[serde(with = "string")] AnyOdd);
+
+    #[test_case("5/2", r#""5/2""#)]
+    #[test_case("2.5", r#""2.5""#)]
+    #[test_case("150.0", r#""150.0""#)]
+    #[test_case("2", r#""2.0""#)]
+    #[test_case("-110", r#""-110""#)]
+    #[test_case("+150", r#""+150""#)]
+    fn any_odd_string(input: &str, expected: &str) {
+        let odd: AnyOdd = input.parse().unwrap();
+        let json = serde_json::to_string(&AnyString(odd)).unwrap();
+        assert_eq!(json, expected);
+
+        let AnyString(deserialized) = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, odd);
+        assert_eq!(deserialized.format(), odd.format());
+    }
+
+    #[test]
+    fn invalid_any_odd_string() {
+        assert!(serde_json::from_str::<AnyString>(r#""abc""#).is_err());
+    }
+}
@@ -0,0 +1,25 @@
+//! Helpers shared by the tests of several modules.
+
+#![allow(clippy::unwrap_used)]
+
+#[cfg(feature = "alloc")]
+use crate::market::{Price, Timestamp};
+#[cfg(feature = "alloc")]
+use crate::odd::AnyOdd;
+
+/// Assert that two floats are equal up to rounding errors.
+pub(crate) fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-12, "{actual} != {expected}");
+}
+
+/// Parse an odd of any format, e.g. `"+150"`.
+#[cfg(feature = "alloc")]
+pub(crate) fn odd(input: &str) -> AnyOdd {
+    input.parse().unwrap()
+}
+
+/// Create a price of a bookmaker from an odd of any format.
+#[cfg(feature = "alloc")]
+pub(crate) fn price(odd: &str, bookmaker: &str, millis: u64) -> Price {
+    Price::new(self::odd(odd), bookmaker, Timestamp::from_millis(millis))
+}
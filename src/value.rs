@@ -0,0 +1,301 @@
+//! The expected value of bets, given the true probability of them winning.
+//!
+//! The true probability is an estimate, e.g. from a model or from a fair reference
+//! price such as a consensus of bookmakers, whose implied probability is
+//! [`DynOdd::implied_probability`](crate::odd::DynOdd::implied_probability). Amounts
+//! are in units of the stake, and ratios are fractions, e.g. `0.05` for 5%.
+//!
+//! Example
+//! ```rust
+//! use wager::odd::DynOdd;
+//! use wager::odds;
+//! use wager::value::{edge, expected_value, expected_value_against, roi};
+//!
+//! // A price of 2.2 on an outcome that is fairly priced at evens.
+//! let probability = odds!(2.0).implied_probability();
+//!
+//! assert!((expected_value(odds!(2.2), probability, 10.0) - 1.0).abs() < 1e-12);
+//! assert!((expected_value_against(odds!(2.2), odds!(2.0), 10.0) - 1.0).abs() < 1e-12);
+//! assert!((roi(odds!(2.2), probability) - 0.1).abs() < 1e-12);
+//! assert!((edge(odds!(2.2), probability) - (0.5 - 1.0 / 2.2)).abs() < 1e-12);
+//! ```
+
+use core::num::NonZeroU32;
+
+use crate::odd::{AnyOdd, Decimal, Odd, OddError};
+
+/// Get the expected profit of a bet, which is negative if the bet is expected to lose
+/// money.
+pub fn expected_value(odd: impl Odd, probability: f64, stake: f64) -> f64 {
+    probability * odd.payout(stake) - stake
+}
+
+/// Get the expected profit of a bet against a fair price for it, i.e. taking the
+/// probability implied by the fair price as the true probability.
+pub fn expected_value_against(odd: impl Odd, fair: impl Odd, stake: f64) -> f64 {
+    expected_value(odd, fair.payout(1.0).recip(), stake)
+}
+
+/// Get the expected return on investment of a bet, i.e. its expected profit for a
+/// stake of one unit.
+pub fn roi(odd: impl Odd, probability: f64) -> f64 {
+    expected_value(odd, probability, 1.0)
+}
+
+/// Get the edge of a bet, i.e. how much the true probability exceeds the probability
+/// implied by the odd, which is the probability needed to break even.
+pub fn edge(odd: impl Odd, probability: f64) -> f64 {
+    probability - odd.payout(1.0).recip()
+}
+
+/// A bet on every leg winning, assuming that the legs are independent.
+///
+/// Example
+/// ```rust
+/// use wager::odd::AnyOdd;
+/// use wager::odds;
+/// use wager::value::Parlay;
+///
+/// let parlay = Parlay::new([
+///     (AnyOdd::from(odds!(-110)), 0.55),
+///     (AnyOdd::from(odds!(6/4)), 0.42),
+/// ]);
+///
+/// assert!((parlay.probability() - 0.55 * 0.42).abs() < 1e-12);
+/// assert!((parlay.payout(1.0) - 21.0 / 11.0 * 2.5).abs() < 1e-12);
+/// assert!(parlay.expected_value(10.0) > 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Parlay {
+    payout: f64,
+    probability: f64,
+}
+
+impl Parlay {
+    /// Create a parlay from the odd of each leg and the true probability of it
+    /// winning.
+    ///
+    /// Without any legs, the parlay is certain to win but only pays back the stake.
+    pub fn new<I, O>(legs: I) -> Self
+    where
+        I: IntoIterator<Item = (O, f64)>,
+        O: Odd,
+    {
+        legs.into_iter().fold(
+            Self {
+                payout: 1.0,
+                probability: 1.0,
+            },
+            |parlay, (odd, probability)| Self {
+                payout: parlay.payout * odd.payout(1.0),
+                probability: parlay.probability * probability,
+            },
+        )
+    }
+
+    /// Get the total (including the stake) payout if every leg wins.
+    pub fn payout(&self, stake: f64) -> f64 {
+        stake * self.payout
+    }
+
+    /// Get the true probability of every leg winning.
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+
+    /// Get the combined odd of the parlay.
+    pub fn to_decimal(&self) -> Result<Decimal, OddError> {
+        Decimal::new(self.payout)
+    }
+
+    /// Get the expected profit of the parlay.
+    pub fn expected_value(&self, stake: f64) -> f64 {
+        self.probability * self.payout(stake) - stake
+    }
+
+    /// Get the expected profit of a bet against a fair price for it, i.e. taking the
+    /// probability implied by the fair price as the true probability.
+    pub fn expected_value_against(odd: impl Odd, fair: impl Odd, stake: f64) -> f64 {
+        expected_value(odd, fair.payout(1.0).recip(), stake)
+    }
+
+    /// Get the expected return on investment of the parlay.
+    pub fn roi(&self) -> f64 {
+        self.expected_value(1.0)
+    }
+
+    /// Get the edge of the parlay over the probability implied by its combined odd.
+    pub fn edge(&self) -> f64 {
+        self.probability - self.payout.recip()
+    }
+}
+
+/// An each-way bet, i.e. two bets of equal stakes: one on the selection winning, and
+/// one on it placing at a fraction of the odds.
+///
+/// The place probability includes the selection winning, as a winner also places.
+///
+/// Example
+/// ```rust
+/// use std::num::NonZeroU32;
+///
+/// use wager::odds;
+/// use wager::value::EachWay;
+///
+/// // 10/1 each-way at a quarter of the odds for a place.
+/// let bet = EachWay::new(odds!(10/1), NonZeroU32::new(4).unwrap());
+/// assert_eq!(bet.place_odd().unwrap().value(), 3.5);
+///
+/// // A stake of 5 on each part.
+/// let expected = 0.1 * 55.0 + 0.3 * 17.5 - 10.0;
+/// assert!((bet.expected_value(0.1, 0.3, 5.0) - expected).abs() < 1e-12);
+/// assert!((bet.roi(0.1, 0.3) - expected / 10.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EachWay {
+    odd: AnyOdd,
+    terms: NonZeroU32,
+}
+
+impl EachWay {
+    /// Create an each-way bet at an odd, whose place part is paid at `1/terms` of its
+    /// odds, e.g. `4` for a quarter of the odds.
+    pub fn new(odd: impl Into<AnyOdd>, terms: NonZeroU32) -> Self {
+        Self {
+            odd: odd.into(),
+            terms,
+        }
+    }
+
+    /// Get the odd of the win part.
+    pub fn odd(&self) -> AnyOdd {
+        self.odd
+    }
+
+    /// Get the fraction of the odds that the place part is paid at, e.g. `4` for a
+    /// quarter of the odds.
+    pub fn terms(&self) -> u32 {
+        self.terms.get()
+    }
+
+    /// Get the odd of the place part.
+    pub fn place_odd(&self) -> Result<Decimal, OddError> {
+        Decimal::new(self.place_payout(1.0))
+    }
+
+    /// Get the total (including the stake) payout of the win part.
+    pub fn win_payout(&self, stake: f64) -> f64 {
+        self.odd.payout(stake)
+    }
+
+    /// Get the total (including the stake) payout of the place part.
+    pub fn place_payout(&self, stake: f64) -> f64 {
+        let profit = self.odd.payout(stake) - stake;
+        stake + profit / f64::from(self.terms.get())
+    }
+
+    /// Get the expected profit of the bet, with the given stake on each part, i.e.
+    /// twice the stake in total.
+    pub fn expected_value(&self, win_probability: f64, place_probability: f64, stake: f64) -> f64 {
+        win_probability * self.win_payout(stake) + place_probability * self.place_payout(stake)
+            - 2.0 * stake
+    }
+
+    /// Get the expected profit of a bet against a fair price for it, i.e. taking the
+    /// probability implied by the fair price as the true probability.
+    pub fn expected_value_against(odd: impl Odd, fair: impl Odd, stake: f64) -> f64 {
+        expected_value(odd, fair.payout(1.0).recip(), stake)
+    }
+
+    /// Get the expected return on investment of the bet, i.e. its expected profit for a
+    /// total stake of one unit.
+    pub fn roi(&self, win_probability: f64, place_probability: f64) -> f64 {
+        self.expected_value(win_probability, place_probability, 0.5)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::odds;
+    use crate::testing::assert_close;
+    use test_case::test_case;
+
+    #[test_case(AnyOdd::from(odds!(2.5)), 0.5, 10.0, 2.5)]
+    #[test_case(AnyOdd::from(odds!(-110)), 0.5, 110.0, -5.0)]
+    #[test_case(AnyOdd::from(odds!(3/1)), 0.25, 4.0, 0.0)]
+    #[test_case(AnyOdd::from(odds!(+150)), 0.0, 10.0, -10.0)]
+    fn single(odd: AnyOdd, probability: f64, stake: f64, expected: f64) {
+        assert_close(expected_value(odd, probability, stake), expected);
+        assert_close(roi(odd, probability), expected / stake);
+    }
+
+    #[test_case(AnyOdd::from(odds!(2.2)), AnyOdd::from(odds!(2.0)), 10.0, 1.0)]
+    #[test_case(AnyOdd::from(odds!(-110)), AnyOdd::from(odds!(+100)), 110.0, -5.0)]
+    #[test_case(AnyOdd::from(odds!(5/2)), AnyOdd::from(odds!(3.4)), 4.0, 14.0 / 3.4 - 4.0)]
+    #[test_case(AnyOdd::from(odds!(1.5)), AnyOdd::from(odds!(1.5)), 1.0, 0.0)]
+    fn against(odd: AnyOdd, fair: AnyOdd, stake: f64, expected: f64) {
+        let probability = fair.payout(1.0).recip();
+
+        assert_close(
+            expected_value_against(odd, fair, stake),
+            expected_value(odd, probability, stake),
+        );
+        assert_close(expected_value_against(odd, fair, stake), expected);
+    }
+
+    #[test_case(AnyOdd::from(odds!(2.5)), 0.5, 0.1)]
+    #[test_case(AnyOdd::from(odds!(-300)), 0.7, -0.05)]
+    #[test_case(AnyOdd::from(odds!(1/1)), 0.5, 0.0)]
+    fn edges(odd: AnyOdd, probability: f64, expected: f64) {
+        assert_close(edge(odd, probability), expected);
+    }
+
+    #[test]
+    fn parlay() {
+        let parlay = Parlay::new([(odds!(2.0), 0.6), (odds!(3.0), 0.4), (odds!(1.5), 0.7)]);
+
+        assert_close(parlay.payout(10.0), 90.0);
+        assert_close(parlay.probability(), 0.168);
+        assert_close(parlay.to_decimal().unwrap().value(), 9.0);
+        assert_close(parlay.expected_value(10.0), 0.168 * 90.0 - 10.0);
+        assert_close(parlay.roi(), 0.168 * 9.0 - 1.0);
+        assert_close(parlay.edge(), 0.168 - 1.0 / 9.0);
+    }
+
+    #[test]
+    fn parlay_empty() {
+        let parlay = Parlay::new(Vec::<(AnyOdd, f64)>::new());
+
+        assert_eq!(parlay.payout(10.0), 10.0);
+        assert_eq!(parlay.probability(), 1.0);
+        assert_eq!(parlay.expected_value(10.0), 0.0);
+    }
+
+    #[test_case(AnyOdd::from(odds!(10/1)), 5, 3.0)]
+    #[test_case(AnyOdd::from(odds!(+400)), 4, 2.0)]
+    #[test_case(AnyOdd::from(odds!(1.5)), 4, 1.125)]
+    fn each_way_place(odd: AnyOdd, terms: u32, expected: f64) {
+        let bet = EachWay::new(odd, NonZeroU32::new(terms).unwrap());
+
+        assert_close(bet.place_odd().unwrap().value(), expected);
+        assert_close(bet.place_payout(2.0), 2.0 * expected);
+        assert_eq!(bet.win_payout(2.0), odd.payout(2.0));
+    }
+
+    #[test]
+    fn each_way_value() {
+        let bet = EachWay::new(odds!(+400), NonZeroU32::new(4).unwrap());
+
+        // Winning pays 5 and 2 for 1, placing only pays 2 for 1.
+        assert_close(
+            bet.expected_value(0.2, 0.5, 1.0),
+            0.2 * 5.0 + 0.5 * 2.0 - 2.0,
+        );
+        assert_close(bet.roi(0.2, 0.5), 0.0);
+        assert_close(bet.roi(0.25, 0.5), 0.125);
+        assert_eq!(bet.terms(), 4);
+        assert_eq!(bet.odd(), AnyOdd::from(odds!(+400)));
+    }
+}